@@ -16,7 +16,7 @@
 
 #[ink::contract]
 mod dao {
-    use ink::{contract_ref, prelude::{string::String, vec}, selector_bytes, storage::StorageVec};
+    use ink::{contract_ref, prelude::{string::String, vec}, selector_bytes, storage::StorageVec, ToAccountId};
     use ink::codegen::Env;
     use minidao_common::*;
     use superdao_traits::{Call, ContractCall, Error, SuperDao, Vote};
@@ -38,13 +38,8 @@ mod dao {
         // Constructor that initializes the values for the contract.
         #[ink(constructor)]
         pub fn new(name: String, superdao: AccountId) -> Self {
+            let mut instance = Self::init(name, superdao);
             // Register your Dao as a member of the Superdao.
-            let mut instance = Self {
-                name,
-                superdao: superdao.into(),
-                voters: StorageVec::new(),
-                value: 0,
-            };
             assert!(instance.superdao.register_member().is_ok(), "Unable to register SuperDao");
             instance
         }
@@ -113,7 +108,7 @@ mod dao {
 
             // let call = Call::Contract(ContractCall {
             //     callee: self.env().account_id(),
-            //     selector: selector_bytes!("update_value"),
+            //     selector: selector_bytes!("SuperdaoExecutable::update_value"),
             //     input: vec![],
             //     transferred_value: 0,
             //     ref_time_limit: 0,
@@ -123,12 +118,6 @@ mod dao {
             Ok(self.superdao.create_proposal(call))
         }
 
-        #[ink(message)]
-        pub fn update_value(&mut self) -> Result<(), DaoError> {
-            self.value = self.value.saturating_add(10);
-            Ok(())
-        }
-
         #[ink(message)]
         pub fn get_value(&mut self) -> u8 {
             self.value
@@ -149,6 +138,54 @@ mod dao {
 
             Ok(())
         }
+
+        fn init(name: String, superdao: AccountId) -> Self {
+            Self {
+                name,
+                superdao: superdao.into(),
+                voters: StorageVec::new(),
+                value: 0,
+            }
+        }
+
+        fn ensure_superdao(&self) -> Result<(), DaoError> {
+            if self.env().caller() != self.superdao.to_account_id() {
+                return Err(DaoError::Unauthorized);
+            }
+            Ok(())
+        }
+    }
+
+    impl SuperdaoExecutable for Dao {
+        #[ink(message)]
+        fn update_value(&mut self) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Success: Increase the value by 10.
+
+            self.ensure_superdao()?;
+            self.value = self.value.saturating_add(10);
+            Ok(())
+        }
     }
 
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::dao::Dao;
+
+        #[ink::test]
+        fn test_update_value() {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            // Skips registering with the Superdao, which cannot be called off-chain.
+            let mut dao = Dao::init(String::from("Dao"), accounts.django);
+
+            // Only the Superdao can update the value.
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.alice);
+            assert_eq!(dao.update_value(), Err(DaoError::Unauthorized));
+            assert_eq!(dao.get_value(), 0);
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(accounts.django);
+            assert_eq!(dao.update_value(), Ok(()));
+            assert_eq!(dao.get_value(), 10);
+        }
+    }
 }
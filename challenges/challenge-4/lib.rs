@@ -17,29 +17,27 @@ mod dao {
         contract_ref,
         prelude::string::String,
         storage::StorageVec,
-        xcm::prelude::*,
+        xcm::{prelude::*, VersionedLocation, VersionedXcm},
         prelude::vec::Vec,
+        ToAccountId,
     };
     use minidao_common::*;
-    use superdao_traits::{Call, ChainCall, Error, SuperDao, Vote};
+    use superdao_traits::{Error, SuperDao, Vote};
 
     #[ink(storage)]
     pub struct Dao {
         superdao: contract_ref!(SuperDao),
         voters: StorageVec<AccountId>,
         name: String,
+        cross_chain: CrossChainState,
     }
 
     impl Dao {
         // Constructor that initializes the values for the contract.
         #[ink(constructor)]
         pub fn new(name: String, superdao: AccountId) -> Self {
+            let mut instance = Self::init(name, superdao);
             // Register your Dao as a member of the Superdao.
-            let mut instance = Self {
-                name,
-                superdao: superdao.into(),
-                voters: StorageVec::new(),
-            };
             assert!(instance.superdao.register_member().is_ok(), "Superdao registration failed!");
             instance
         }
@@ -93,38 +91,106 @@ mod dao {
         }
 
         #[ink(message)]
+        #[allow(clippy::too_many_arguments)]
         pub fn create_superdao_cross_chain_proposal(
             &mut self,
             voter: AccountId,
+            dest: Location,
             encoded_extrinsic: Vec<u8>,
+            origin_kind: OriginKind,
+            fee_asset: Option<Location>,
             fee_max: Balance,
             ref_time: u64,
             proof_size: u64,
+            label: Option<String>,
         ) -> Result<Result<u32, Error>, DaoError> {
             // - Error: Throw error `DaoError::VoterNotRegistered` if the voter is not registered
-            // - Success: Create a SuperDao proposal to execute a cross-chain message.
+            // - Error: Throw error `DaoError::UnsupportedDestination` if `dest` is not the relay chain or a parachain
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Error: Throw error `DaoError::WeightLimitExceeded` if the weight exceeds the destination's limit
+            // - Error: Throw error `DaoError::EmptyCall`, `DaoError::CallTooLarge` or `DaoError::CallNotAllowed`
+            //   if `encoded_extrinsic` is empty, too large or not in the destination's call allowlist
+            // - Error: Throw error `DaoError::OriginKindNotAllowed` if `origin_kind` is not allowed on `dest`
+            // - Error: Throw error `DaoError::LabelTooLong` if `label` is too long
+            // - Success: Create a SuperDao proposal to execute a cross-chain message, paying fees with
+            //   `fee_asset` or the destination's default fee asset. Unspent fees are refunded to the
+            //   Superdao's sovereign account and the execution outcome is reported back to the Dao.
 
             if !self.has_voter(voter) {
                 return Err(DaoError::VoterNotRegistered);
             }
 
-            let asset: Asset = (Location::parent(), fee_max).into();
-            let ah = Junctions::from([Parachain(1000)]);
-            let dest: Location = Location { parents: 1, interior: ah};
+            let proposal = self.cross_chain.transact_proposal(
+                &dest,
+                encoded_extrinsic,
+                origin_kind,
+                fee_asset,
+                fee_max,
+                Weight::from_parts(ref_time, proof_size),
+                label,
+                self.env().account_id(),
+                self.superdao.to_account_id(),
+            )?;
+            Ok(self.cross_chain.forward(proposal, |call| self.superdao.create_proposal(call)))
+        }
 
-            let message: Xcm<()> = Xcm::builder()
-                .withdraw_asset(asset.clone().into())
-                .buy_execution(asset.clone(), Unlimited)
-                .transact(
-                    OriginKind::SovereignAccount,
-                    Weight::from_parts(ref_time, proof_size),
-                    encoded_extrinsic.into(),
-                )
-                .build();
+        #[ink(message)]
+        pub fn create_superdao_xcm_proposal(
+            &mut self,
+            voter: AccountId,
+            dest: Location,
+            program: Vec<ProposalInstruction>,
+            label: Option<String>,
+        ) -> Result<Result<u32, Error>, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the voter is not registered
+            // - Error: Throw error `DaoError::UnsupportedDestination` if `dest` is not the relay chain or a parachain
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Error: Throw error `DaoError::EmptyXcmProgram`, `DaoError::XcmProgramNotPaid`,
+            //   `DaoError::NestedXcmProgram` or `DaoError::XcmProgramTooLong` if `program` is invalid
+            // - Error: Throw error `DaoError::WeightLimitExceeded` if a `Transact` exceeds the destination's weight limit
+            // - Error: Throw error `DaoError::EmptyCall`, `DaoError::CallTooLarge` or `DaoError::CallNotAllowed`
+            //   if a `Transact` dispatches an empty, too large or not allowed call
+            // - Error: Throw error `DaoError::OriginKindNotAllowed` if a `Transact` uses an origin kind not allowed on the destination
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `program` moves assets to, reports to
+            //   or runs a program on a chain that is not in the allowlist
+            // - Error: Throw error `DaoError::LabelTooLong` if `label` is too long
+            // - Success: Create a SuperDao proposal to execute `program` on `dest`.
 
-            let call = Call::Chain(ChainCall::new(&dest, &message));
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
 
-            Ok(self.superdao.create_proposal(call))
+            let proposal = self.cross_chain.xcm_proposal(&dest, program, label)?;
+            Ok(self.cross_chain.forward(proposal, |call| self.superdao.create_proposal(call)))
+        }
+
+        #[ink(message)]
+        pub fn create_superdao_transfer_proposal(
+            &mut self,
+            voter: AccountId,
+            dest_chain: Location,
+            beneficiary: Location,
+            asset: Location,
+            amount: Balance,
+            fee_max: Balance,
+            label: Option<String>,
+        ) -> Result<Result<u32, Error>, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the voter is not registered
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if Asset Hub or `dest_chain` is not in the allowlist
+            // - Error: Throw error `DaoError::UnsupportedAsset` if `asset` cannot be sent to `dest_chain`
+            // - Error: Throw error `DaoError::FeeExceedsAmount` if fees may consume the whole `amount`
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if the program reaches a chain that is not in the allowlist
+            // - Error: Throw error `DaoError::LabelTooLong` if `label` is too long
+            // - Success: Create a SuperDao proposal to pay `amount` of `asset` from the Superdao's
+            //   treasury on Asset Hub to `beneficiary` on `dest_chain`.
+
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+
+            let proposal =
+                self.cross_chain.transfer_proposal(&dest_chain, beneficiary, asset, amount, fee_max, label)?;
+            Ok(self.cross_chain.forward(proposal, |call| self.superdao.create_proposal(call)))
         }
 
         #[ink(message)]
@@ -142,16 +208,453 @@ mod dao {
 
             Ok(())
         }
+
+        #[ink(message)]
+        pub fn create_dao_xcm_proposal(
+            &mut self,
+            voter: AccountId,
+            dest: Option<Location>,
+            program: Vec<ProposalInstruction>,
+        ) -> Result<u32, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the voter is not registered
+            // - Error: Throw error `DaoError::UnsupportedDestination` if `dest` is not the relay chain or a parachain
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Error: Throw error `DaoError::EmptyXcmProgram`, `DaoError::XcmProgramNotPaid`,
+            //   `DaoError::NestedXcmProgram` or `DaoError::XcmProgramTooLong` if `program` is invalid
+            // - Error: Throw error `DaoError::WeightLimitExceeded` if a `Transact` exceeds the destination's weight limit,
+            //   or if there is no `dest` and the `Transact`s exceed the local weight limit in total
+            // - Error: Throw error `DaoError::EmptyCall`, `DaoError::CallTooLarge` or `DaoError::CallNotAllowed`
+            //   if a `Transact` dispatches an empty, too large or not allowed call
+            // - Error: Throw error `DaoError::OriginKindNotAllowed` if a `Transact` uses an origin kind not allowed on the destination
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `program` moves assets to, reports to
+            //   or runs a program on a chain that is not in the allowlist
+            // - Success: Create a Dao proposal to send `program` to `dest`, or to execute it on Pop
+            //   Network if there is no `dest`, once a majority of voters approve it.
+
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+
+            self.cross_chain.create_dao_proposal(dest, program)
+        }
+
+        #[ink(message)]
+        pub fn vote_dao_proposal(&mut self, proposal_id: u32, vote: bool) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the caller is not a registered voter
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal is not created
+            // - Error: Throw error `DaoError::ProposalAlreadyExecuted` if the proposal is executed
+            // - Error: Throw error `DaoError::VoterAlreadyVoted` if the caller voted the proposal
+            // - Success: Vote a Dao proposal as the caller.
+
+            let voter = self.env().caller();
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+
+            self.cross_chain.vote_dao_proposal(proposal_id, voter, vote)
+        }
+
+        #[ink(message)]
+        pub fn execute_dao_proposal(&mut self, proposal_id: u32) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal is not created
+            // - Error: Throw error `DaoError::ProposalAlreadyExecuted` if the proposal is executed
+            // - Error: Throw error `DaoError::ProposalNotPassed` if a majority of current voters did not approve the proposal
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if the destination, or a chain the program
+            //   reaches, was removed from the allowlist
+            // - Error: Throw error `DaoError::WeightLimitExceeded` if a `Transact` exceeds the destination's weight limit,
+            //   or if there is no destination and the `Transact`s exceed the local weight limit in total
+            // - Error: Throw error `DaoError::EmptyCall`, `DaoError::CallTooLarge` or `DaoError::CallNotAllowed`
+            //   if a `Transact` dispatches an empty, too large or not allowed call
+            // - Error: Throw error `DaoError::OriginKindNotAllowed` if a `Transact` uses an origin kind not allowed on the destination
+            // - Error: Throw error `DaoError::XcmFailed` if the message cannot be sent or executed
+            // - Success: Send the proposal's program to its destination, or execute it on Pop Network.
+
+            let (dest, message) =
+                self.cross_chain.passed_dao_proposal(proposal_id, self.voters.len(), |voter| self.has_voter(*voter))?;
+            let message = VersionedXcm::from(message);
+            match dest {
+                Some(chain) => {
+                    self.env()
+                        .xcm_send(&VersionedLocation::from(chain.location()), &message)
+                        .map_err(|_| DaoError::XcmFailed)?;
+                },
+                None => self.env().xcm_execute(&message).map_err(|_| DaoError::XcmFailed)?,
+            }
+
+            self.cross_chain.mark_dao_proposal_executed(proposal_id)
+        }
+
+        #[ink(message)]
+        pub fn get_dao_proposal(&self, proposal_id: u32) -> Result<LocalProposal, DaoError> {
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal is not created
+            // - Success: Returns the Dao proposal's program, votes and execution state.
+
+            self.cross_chain.dao_proposal(proposal_id)
+        }
+
+        #[ink(message)]
+        pub fn get_forwarded_proposal(&self, proposal_id: u32) -> Result<ForwardedProposal, DaoError> {
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal was not forwarded by the Dao
+            // - Success: Returns the destination and execution status of a proposal forwarded to the Superdao.
+
+            self.cross_chain.forwarded_proposal(proposal_id)
+        }
+
+        #[ink(message)]
+        pub fn describe_proposal(&self, proposal_id: u32) -> Result<ProposalSummary, DaoError> {
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal was not forwarded by the Dao
+            // - Success: Returns the label, destination, fees, weight and dispatched pallet and call
+            //   indices of a proposal forwarded to the Superdao, along with its execution status.
+
+            self.cross_chain.forwarded_proposal(proposal_id).map(|proposal| proposal.summary())
+        }
+
+        #[ink(message)]
+        pub fn record_query_response(&mut self, query_id: u64, response: Response) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the query responder
+            // - Error: Throw error `DaoError::QueryNotFound` if the query is not pending
+            // - Error: Throw error `DaoError::UnexpectedResponse` if `response` is not a transact status or error report
+            // - Success: Record the reported execution outcome on the forwarded proposal.
+            //
+            // Query responses are handled by pallet-xcm, which never registered the Dao's query ids,
+            // so contracts cannot receive them. Instead a single responder appointed by the Superdao
+            // relays them. The Dao cannot verify what the destination actually sent, so it fully trusts
+            // the responder: a faulty or compromised responder can mark any pending proposal as
+            // succeeded or failed. There is no responder until the Superdao sets one, so statuses stay
+            // pending until then.
+
+            self.cross_chain.record_query_response(self.env().caller(), query_id, &response)
+        }
+
+        #[ink(message)]
+        pub fn set_query_responder(&mut self, responder: Option<AccountId>) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Success: Replace the account trusted to record query responses, or remove it with `None`.
+
+            self.ensure_superdao()?;
+            self.cross_chain.set_query_responder(responder);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn allow_destination(&mut self, dest: Location, fee_asset: Location) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Error: Throw error `DaoError::UnsupportedDestination` if `dest` is not the relay chain or a parachain
+            // - Success: Add `dest` to the destinations cross-chain proposals can target, paying fees
+            //   with `fee_asset` by default. Updates the default fee asset if `dest` is already allowed.
+
+            self.ensure_superdao()?;
+            self.cross_chain.allow_destination(&dest, fee_asset)
+        }
+
+        #[ink(message)]
+        pub fn disallow_destination(&mut self, dest: Location) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Remove `dest` from the destinations cross-chain proposals can target.
+
+            self.ensure_superdao()?;
+            self.cross_chain.disallow_destination(&dest)
+        }
+
+        #[ink(message)]
+        pub fn is_destination_allowed(&self, dest: Location) -> bool {
+            self.cross_chain.destination(&dest).is_ok()
+        }
+
+        #[ink(message)]
+        pub fn default_fee_asset(&self, dest: Location) -> Result<Location, DaoError> {
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Returns the asset used to pay fees on `dest` when a proposal does not specify one.
+
+            let (_, config) = self.cross_chain.destination(&dest)?;
+            Ok(config.fee_asset)
+        }
+
+        #[ink(message)]
+        pub fn set_fee_table(&mut self, dest: Location, fees: FeeTable) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Replace the weights, fee rates and weight limit used for proposals to `dest`.
+
+            self.ensure_superdao()?;
+            self.cross_chain.set_fee_table(&dest, fees)
+        }
+
+        #[ink(message)]
+        pub fn fee_table(&self, dest: Location) -> Result<FeeTable, DaoError> {
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Returns the weights, fee rates and weight limit used for proposals to `dest`.
+
+            let (_, config) = self.cross_chain.destination(&dest)?;
+            Ok(config.fees)
+        }
+
+        #[ink(message)]
+        pub fn set_allowed_calls(&mut self, dest: Location, allowed_calls: Option<Vec<(u8, u8)>>) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Restrict the calls proposals may dispatch on `dest` to the given
+            //   `(pallet_index, call_index)` pairs, or lift the restriction with `None`.
+
+            self.ensure_superdao()?;
+            self.cross_chain.set_allowed_calls(&dest, allowed_calls)
+        }
+
+        #[ink(message)]
+        pub fn set_allowed_origin_kinds(&mut self, dest: Location, origin_kinds: Vec<OriginKind>) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Replace the origin kinds proposals may dispatch calls with on `dest`.
+
+            self.ensure_superdao()?;
+            self.cross_chain.set_allowed_origin_kinds(&dest, origin_kinds)
+        }
+
+        #[ink(message)]
+        pub fn allowed_origin_kinds(&self, dest: Location) -> Result<Vec<OriginKind>, DaoError> {
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Returns the origin kinds proposals may dispatch calls with on `dest`.
+
+            let (_, config) = self.cross_chain.destination(&dest)?;
+            Ok(config.allowed_origin_kinds)
+        }
+
+        #[ink(message)]
+        pub fn allowed_calls(&self, dest: Location) -> Result<Option<Vec<(u8, u8)>>, DaoError> {
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Success: Returns the `(pallet_index, call_index)` pairs proposals may dispatch on `dest`.
+
+            let (_, config) = self.cross_chain.destination(&dest)?;
+            Ok(config.allowed_calls)
+        }
+
+        #[ink(message)]
+        pub fn set_local_weight_limit(&mut self, weight: Weight) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::Unauthorized` if the caller is not the Superdao
+            // - Success: Replace the limit on the total weight of the `Transact`s in a Dao proposal
+            //   executed on Pop Network.
+
+            self.ensure_superdao()?;
+            self.cross_chain.set_local_weight_limit(weight);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn local_weight_limit(&self) -> Weight {
+            self.cross_chain.local_weight_limit()
+        }
+
+        #[ink(message)]
+        pub fn estimate_cross_chain_proposal(
+            &self,
+            dest: Location,
+            encoded_extrinsic: Vec<u8>,
+        ) -> Result<(Weight, Balance), DaoError> {
+            // - Error: Throw error `DaoError::DestinationNotAllowed` if `dest` is not in the allowlist
+            // - Error: Throw error `DaoError::WeightLimitExceeded` if the estimated weight exceeds the destination's limit
+            // - Success: Returns the recommended weight and fee for dispatching `encoded_extrinsic` on `dest`.
+
+            self.cross_chain.estimate(&dest, &encoded_extrinsic)
+        }
+
+        fn init(name: String, superdao: AccountId) -> Self {
+            Self {
+                name,
+                superdao: superdao.into(),
+                voters: StorageVec::new(),
+                cross_chain: CrossChainState::new(),
+            }
+        }
+
+        fn ensure_superdao(&self) -> Result<(), DaoError> {
+            if self.env().caller() != self.superdao.to_account_id() {
+                return Err(DaoError::Unauthorized);
+            }
+            Ok(())
+        }
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
         use crate::dao::Dao;
+        use ink::prelude::vec;
 
         #[ink::test]
         fn test_vote_superdao_cross_chain_proposal() {
             todo!("Challenge 4");
         }
+
+        // Skips registering with the Superdao, which cannot be called off-chain.
+        fn dao_with_voters() -> (Dao, ink::env::test::DefaultAccounts<ink::env::DefaultEnvironment>) {
+            let accounts = ink::env::test::default_accounts::<ink::env::DefaultEnvironment>();
+            let mut dao = Dao::init(String::from("Dao"), accounts.django);
+            for voter in [accounts.alice, accounts.bob, accounts.charlie] {
+                dao.register_voter(voter).unwrap();
+            }
+            (dao, accounts)
+        }
+
+        fn set_caller(caller: AccountId) {
+            ink::env::test::set_caller::<ink::env::DefaultEnvironment>(caller);
+        }
+
+        fn local_program(beneficiary: Location) -> Vec<ProposalInstruction> {
+            vec![
+                ProposalInstruction::WithdrawAsset { asset: Location::parent(), amount: 100 },
+                ProposalInstruction::BuyExecution { asset: Location::parent(), amount: 10 },
+                ProposalInstruction::DepositAsset { max_assets: 1, beneficiary },
+            ]
+        }
+
+        fn local_account(account: AccountId) -> Location {
+            Location::new(0, [AccountId32 { network: None, id: *account.as_ref() }])
+        }
+
+        #[ink::test]
+        fn test_vote_dao_proposal_twice() {
+            let (mut dao, accounts) = dao_with_voters();
+            let proposal_id =
+                dao.create_dao_xcm_proposal(accounts.alice, None, local_program(local_account(accounts.alice))).unwrap();
+
+            set_caller(accounts.bob);
+            assert_eq!(dao.vote_dao_proposal(proposal_id, true), Ok(()));
+            assert_eq!(dao.vote_dao_proposal(proposal_id, false), Err(DaoError::VoterAlreadyVoted));
+
+            set_caller(accounts.eve);
+            assert_eq!(dao.vote_dao_proposal(proposal_id, true), Err(DaoError::VoterNotRegistered));
+            assert_eq!(dao.get_dao_proposal(proposal_id).unwrap().ayes, vec![accounts.bob]);
+        }
+
+        #[ink::test]
+        fn test_execute_dao_proposal_not_passed() {
+            let (mut dao, accounts) = dao_with_voters();
+            let proposal_id =
+                dao.create_dao_xcm_proposal(accounts.alice, None, local_program(local_account(accounts.alice))).unwrap();
+
+            // One vote out of three voters is not a majority.
+            assert_eq!(dao.vote_dao_proposal(proposal_id, true), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(dao.vote_dao_proposal(proposal_id, false), Ok(()));
+            assert_eq!(dao.execute_dao_proposal(proposal_id), Err(DaoError::ProposalNotPassed));
+            assert_eq!(dao.execute_dao_proposal(proposal_id + 1), Err(DaoError::ProposalDoesNotExist));
+        }
+
+        #[ink::test]
+        fn test_execute_dao_proposal_after_deregister() {
+            let (mut dao, accounts) = dao_with_voters();
+            let proposal_id =
+                dao.create_dao_xcm_proposal(accounts.alice, None, local_program(local_account(accounts.alice))).unwrap();
+            assert_eq!(dao.vote_dao_proposal(proposal_id, true), Ok(()));
+            set_caller(accounts.bob);
+            assert_eq!(dao.vote_dao_proposal(proposal_id, true), Ok(()));
+
+            // Bob's vote no longer counts after deregistering, leaving one vote out of two voters.
+            assert_eq!(dao.deregister_voter(accounts.bob), Ok(()));
+            assert_eq!(dao.execute_dao_proposal(proposal_id), Err(DaoError::ProposalNotPassed));
+        }
+
+        #[ink::test]
+        fn test_execute_dao_proposal_twice() {
+            let (mut dao, accounts) = dao_with_voters();
+            let proposal_id =
+                dao.create_dao_xcm_proposal(accounts.alice, None, local_program(local_account(accounts.alice))).unwrap();
+            // XCM cannot be executed off-chain, so the proposal is marked as executed directly.
+            assert_eq!(dao.cross_chain.mark_dao_proposal_executed(proposal_id), Ok(()));
+
+            assert_eq!(dao.execute_dao_proposal(proposal_id), Err(DaoError::ProposalAlreadyExecuted));
+            set_caller(accounts.charlie);
+            assert_eq!(dao.vote_dao_proposal(proposal_id, true), Err(DaoError::ProposalAlreadyExecuted));
+        }
+
+        #[ink::test]
+        fn test_create_dao_xcm_proposal_checks_reached_chains() {
+            let (mut dao, accounts) = dao_with_voters();
+            let sibling = Location::new(1, [Parachain(2000), AccountId32 { network: None, id: [1; 32] }]);
+            assert_eq!(
+                dao.create_dao_xcm_proposal(accounts.alice, None, local_program(sibling.clone())),
+                Err(DaoError::DestinationNotAllowed)
+            );
+            assert_eq!(
+                dao.create_dao_xcm_proposal(
+                    accounts.alice,
+                    Some(ASSET_HUB.location()),
+                    local_program(sibling)
+                ),
+                Err(DaoError::DestinationNotAllowed)
+            );
+
+            let asset_hub = Location::new(1, [Parachain(1000), AccountId32 { network: None, id: [1; 32] }]);
+            assert_eq!(dao.create_dao_xcm_proposal(accounts.alice, None, local_program(asset_hub)), Ok(0));
+        }
+
+        #[ink::test]
+        fn test_record_query_response_without_responder() {
+            let (mut dao, accounts) = dao_with_voters();
+            assert_eq!(dao.record_query_response(0, Response::Null), Err(DaoError::Unauthorized));
+
+            // Only the Superdao can appoint a responder.
+            assert_eq!(dao.set_query_responder(Some(accounts.alice)), Err(DaoError::Unauthorized));
+            set_caller(accounts.django);
+            assert_eq!(dao.set_query_responder(Some(accounts.alice)), Ok(()));
+            set_caller(accounts.alice);
+            assert_eq!(dao.record_query_response(0, Response::Null), Err(DaoError::QueryNotFound));
+        }
+
+        #[ink::test]
+        fn test_record_query_response_not_responder() {
+            let (mut dao, accounts) = dao_with_voters();
+            set_caller(accounts.django);
+            assert_eq!(dao.set_query_responder(Some(accounts.alice)), Ok(()));
+
+            // Neither voters nor the Superdao can record responses in place of the responder.
+            for caller in [accounts.bob, accounts.django] {
+                set_caller(caller);
+                assert_eq!(dao.record_query_response(0, Response::Null), Err(DaoError::Unauthorized));
+            }
+
+            // Removing the responder leaves no one able to record responses.
+            assert_eq!(dao.set_query_responder(None), Ok(()));
+            set_caller(accounts.alice);
+            assert_eq!(dao.record_query_response(0, Response::Null), Err(DaoError::Unauthorized));
+        }
+
+        #[ink::test]
+        fn test_create_dao_xcm_proposal_local_weight_limit() {
+            let (mut dao, accounts) = dao_with_voters();
+            let mut program = local_program(local_account(accounts.alice));
+            program.push(ProposalInstruction::Transact {
+                origin_kind: OriginKind::SovereignAccount,
+                ref_time: LOCAL_WEIGHT_LIMIT.ref_time() + 1,
+                proof_size: 0,
+                call: vec![0, 0],
+            });
+            assert_eq!(
+                dao.create_dao_xcm_proposal(accounts.alice, None, program),
+                Err(DaoError::WeightLimitExceeded)
+            );
+        }
+
+        #[ink::test]
+        fn test_create_superdao_transfer_proposal_unsupported_asset() {
+            let (mut dao, accounts) = dao_with_voters();
+            set_caller(accounts.django);
+            assert_eq!(dao.allow_destination(Location::parent(), Location::here()), Ok(()));
+
+            // The relay chain only accepts DOT.
+            let beneficiary = Location::new(0, [AccountId32 { network: None, id: [1; 32] }]);
+            let usdt = Location::new(0, [PalletInstance(50), GeneralIndex(1984)]);
+            let result = dao.create_superdao_transfer_proposal(
+                accounts.alice,
+                Location::parent(),
+                beneficiary,
+                usdt,
+                100,
+                10,
+                None,
+            );
+            assert!(matches!(result, Err(DaoError::UnsupportedAsset)));
+        }
     }
 }
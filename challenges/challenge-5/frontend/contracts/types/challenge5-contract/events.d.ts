@@ -1,6 +1,47 @@
 // Generated by dedot cli
 
 import type { GenericSubstrateApi } from 'dedot/types';
+import type { AccountId32 } from 'dedot/codecs';
 import type { GenericContractEvents, GenericContractEvent } from 'dedot/contracts';
 
-export interface ContractEvents<ChainApi extends GenericSubstrateApi> extends GenericContractEvents<ChainApi> {}
+export interface ContractEvents<ChainApi extends GenericSubstrateApi> extends GenericContractEvents<ChainApi> {
+  /**
+   *
+   * @signature_topic: 0xe1c00a863be6d9f860e3d8955f7d8fc3f352db7d57ad68e137ed2bdbb77d750f
+   **/
+  Deposited: GenericContractEvent<
+    'Deposited',
+    {
+      /**
+       *
+       * @indexed: true
+       **/
+      from: AccountId32;
+      /**
+       *
+       * @indexed: false
+       **/
+      amount: bigint;
+    }
+  >;
+
+  /**
+   *
+   * @signature_topic: 0x4061eaf4ab3bc4c3deaeaf56afdf5355cae92675bd24ab0d03512fa94d92255e
+   **/
+  Payout: GenericContractEvent<
+    'Payout',
+    {
+      /**
+       *
+       * @indexed: true
+       **/
+      beneficiary: AccountId32;
+      /**
+       *
+       * @indexed: false
+       **/
+      amount: bigint;
+    }
+  >;
+}
@@ -1,7 +1,7 @@
 // Generated by dedot cli
 
 import type { GenericSubstrateApi } from 'dedot/types';
-import type { Result, AccountId32Like, BytesLike } from 'dedot/codecs';
+import type { Result, AccountId32Like, BytesLike, FixedBytes } from 'dedot/codecs';
 import type {
   GenericContractQuery,
   GenericContractQueryCall,
@@ -9,7 +9,21 @@ import type {
   GenericContractCallResult,
   ContractCallResult,
 } from 'dedot/contracts';
-import type { InkPrimitivesLangError, MinidaoCommonDaoError, SuperdaoTraitsError } from './types';
+import type {
+  InkPrimitivesLangError,
+  MinidaoCommonDaoError,
+  SuperdaoTraitsError,
+  StagingXcmV4Location,
+  XcmV3OriginKind,
+  MinidaoCommonCrossChainProposalInstructionLike,
+  MinidaoCommonTreasurySpendingLimit,
+  MinidaoCommonCrossChainLocalProposal,
+  MinidaoCommonCrossChainForwardedProposal,
+  MinidaoCommonCrossChainProposalSummary,
+  StagingXcmV4ResponseLike,
+  MinidaoCommonCrossChainFeeTable,
+  SpWeightsWeightV2Weight,
+} from './types';
 
 export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends GenericContractQuery<ChainApi> {
   /**
@@ -71,10 +85,14 @@ export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends Gen
   /**
    *
    * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location} dest
    * @param {BytesLike} encodedExtrinsic
+   * @param {XcmV3OriginKind} originKind
+   * @param {StagingXcmV4Location | undefined} feeAsset
    * @param {bigint} feeMax
    * @param {bigint} refTime
    * @param {bigint} proofSize
+   * @param {string | undefined} label
    * @param {ContractCallOptions} options
    *
    * @selector 0x547cc8db
@@ -83,10 +101,72 @@ export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends Gen
     ChainApi,
     (
       voter: AccountId32Like,
+      dest: StagingXcmV4Location,
       encodedExtrinsic: BytesLike,
+      originKind: XcmV3OriginKind,
+      feeAsset: StagingXcmV4Location | undefined,
       feeMax: bigint,
       refTime: bigint,
       proofSize: bigint,
+      label: string | undefined,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<Result<number, SuperdaoTraitsError>, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location} dest
+   * @param {Array<MinidaoCommonCrossChainProposalInstructionLike>} program
+   * @param {string | undefined} label
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x4df5932a
+   **/
+  createSuperdaoXcmProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      voter: AccountId32Like,
+      dest: StagingXcmV4Location,
+      program: Array<MinidaoCommonCrossChainProposalInstructionLike>,
+      label: string | undefined,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<Result<number, SuperdaoTraitsError>, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location} destChain
+   * @param {StagingXcmV4Location} beneficiary
+   * @param {StagingXcmV4Location} asset
+   * @param {bigint} amount
+   * @param {bigint} feeMax
+   * @param {string | undefined} label
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x3de65d46
+   **/
+  createSuperdaoTransferProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      voter: AccountId32Like,
+      destChain: StagingXcmV4Location,
+      beneficiary: StagingXcmV4Location,
+      asset: StagingXcmV4Location,
+      amount: bigint,
+      feeMax: bigint,
+      label: string | undefined,
       options?: ContractCallOptions,
     ) => Promise<
       GenericContractCallResult<
@@ -99,6 +179,12 @@ export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends Gen
   /**
    *
    * @param {AccountId32Like} voter
+   * @param {AccountId32Like} callee
+   * @param {FixedBytes<4>} selector
+   * @param {BytesLike} input
+   * @param {bigint} transferredValue
+   * @param {bigint} refTimeLimit
+   * @param {boolean} allowReentry
    * @param {ContractCallOptions} options
    *
    * @selector 0x0071768f
@@ -107,6 +193,12 @@ export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends Gen
     ChainApi,
     (
       voter: AccountId32Like,
+      callee: AccountId32Like,
+      selector: FixedBytes<4>,
+      input: BytesLike,
+      transferredValue: bigint,
+      refTimeLimit: bigint,
+      allowReentry: boolean,
       options?: ContractCallOptions,
     ) => Promise<
       GenericContractCallResult<
@@ -118,17 +210,99 @@ export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends Gen
 
   /**
    *
+   * @param {bigint} budget
    * @param {ContractCallOptions} options
    *
-   * @selector 0x9bcaeb73
+   * @selector 0x61998a69
    **/
-  updateValue: GenericContractQueryCall<
+  setCallValueBudget: GenericContractQueryCall<
     ChainApi,
     (
+      budget: bigint,
       options?: ContractCallOptions,
     ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
   >;
 
+  /**
+   *
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xd68390c7
+   **/
+  callValueBudget: GenericContractQueryCall<
+    ChainApi,
+    (options?: ContractCallOptions) => Promise<GenericContractCallResult<bigint, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x2d10c9bd
+   **/
+  deposit: GenericContractQueryCall<
+    ChainApi,
+    (options?: ContractCallOptions) => Promise<GenericContractCallResult<[], ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x9f6852a7
+   **/
+  treasuryBalance: GenericContractQueryCall<
+    ChainApi,
+    (options?: ContractCallOptions) => Promise<GenericContractCallResult<bigint, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like} beneficiary
+   * @param {bigint} amount
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x975bccdc
+   **/
+  spend: GenericContractQueryCall<
+    ChainApi,
+    (
+      beneficiary: AccountId32Like,
+      amount: bigint,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {[bigint, number] | undefined} limit
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xcd7a60b2
+   **/
+  setSpendingLimit: GenericContractQueryCall<
+    ChainApi,
+    (
+      limit: [bigint, number] | undefined,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x50105052
+   **/
+  spendingLimit: GenericContractQueryCall<
+    ChainApi,
+    (
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<MinidaoCommonTreasurySpendingLimit | undefined, ContractCallResult<ChainApi>>
+    >
+  >;
+
   /**
    *
    * @param {ContractCallOptions} options
@@ -158,4 +332,382 @@ export interface ContractQuery<ChainApi extends GenericSubstrateApi> extends Gen
       options?: ContractCallOptions,
     ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
   >;
+
+  /**
+   *
+   * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location | undefined} dest
+   * @param {Array<MinidaoCommonCrossChainProposalInstructionLike>} program
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x4fc1648d
+   **/
+  createDaoXcmProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      voter: AccountId32Like,
+      dest: StagingXcmV4Location | undefined,
+      program: Array<MinidaoCommonCrossChainProposalInstructionLike>,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<number, MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {boolean} vote
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x61e73f73
+   **/
+  voteDaoProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      proposalId: number,
+      vote: boolean,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xd3666975
+   **/
+  executeDaoProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      proposalId: number,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x6dbfcc40
+   **/
+  getDaoProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      proposalId: number,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<MinidaoCommonCrossChainLocalProposal, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xe3c64da4
+   **/
+  getForwardedProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      proposalId: number,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<MinidaoCommonCrossChainForwardedProposal, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x2867ae1f
+   **/
+  describeProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      proposalId: number,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<MinidaoCommonCrossChainProposalSummary, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {bigint} queryId
+   * @param {StagingXcmV4ResponseLike} response
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x4ea77907
+   **/
+  recordQueryResponse: GenericContractQueryCall<
+    ChainApi,
+    (
+      queryId: bigint,
+      response: StagingXcmV4ResponseLike,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like | undefined} responder
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xeb09e686
+   **/
+  setQueryResponder: GenericContractQueryCall<
+    ChainApi,
+    (
+      responder: AccountId32Like | undefined,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {StagingXcmV4Location} feeAsset
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x9321a7c5
+   **/
+  allowDestination: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      feeAsset: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x58b9b7a1
+   **/
+  disallowDestination: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x047acdf2
+   **/
+  isDestinationAllowed: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<boolean, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xb2d9d762
+   **/
+  defaultFeeAsset: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<Result<StagingXcmV4Location, MinidaoCommonDaoError>, ContractCallResult<ChainApi>>
+    >
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {MinidaoCommonCrossChainFeeTable} fees
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x1a2f0076
+   **/
+  setFeeTable: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      fees: MinidaoCommonCrossChainFeeTable,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x481b31df
+   **/
+  feeTable: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<MinidaoCommonCrossChainFeeTable, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {Array<[number, number]> | undefined} allowedCalls
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0xe1907e65
+   **/
+  setAllowedCalls: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      allowedCalls: Array<[number, number]> | undefined,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {Array<XcmV3OriginKind>} originKinds
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x99ffce62
+   **/
+  setAllowedOriginKinds: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      originKinds: Array<XcmV3OriginKind>,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x86d41d6e
+   **/
+  allowedOriginKinds: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<Result<Array<XcmV3OriginKind>, MinidaoCommonDaoError>, ContractCallResult<ChainApi>>
+    >
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x4651ab7b
+   **/
+  allowedCalls: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<Array<[number, number]> | undefined, MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {SpWeightsWeightV2Weight} weight
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x9e568f66
+   **/
+  setLocalWeightLimit: GenericContractQueryCall<
+    ChainApi,
+    (
+      weight: SpWeightsWeightV2Weight,
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x7ae4c885
+   **/
+  localWeightLimit: GenericContractQueryCall<
+    ChainApi,
+    (
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<SpWeightsWeightV2Weight, ContractCallResult<ChainApi>>>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {BytesLike} encodedExtrinsic
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x13a16961
+   **/
+  estimateCrossChainProposal: GenericContractQueryCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      encodedExtrinsic: BytesLike,
+      options?: ContractCallOptions,
+    ) => Promise<
+      GenericContractCallResult<
+        Result<[SpWeightsWeightV2Weight, bigint], MinidaoCommonDaoError>,
+        ContractCallResult<ChainApi>
+      >
+    >
+  >;
+
+  /**
+   *
+   * @param {ContractCallOptions} options
+   *
+   * @selector 0x69c98218
+   **/
+  superdaoExecutableUpdateValue: GenericContractQueryCall<
+    ChainApi,
+    (
+      options?: ContractCallOptions,
+    ) => Promise<GenericContractCallResult<Result<[], MinidaoCommonDaoError>, ContractCallResult<ChainApi>>>
+  >;
 }
@@ -1,13 +1,21 @@
 // Generated by dedot cli
 
 import type { GenericSubstrateApi } from 'dedot/types';
-import type { AccountId32Like, BytesLike } from 'dedot/codecs';
+import type { AccountId32Like, BytesLike, FixedBytes } from 'dedot/codecs';
 import type {
   GenericContractTx,
   GenericContractTxCall,
   ContractTxOptions,
   ContractSubmittableExtrinsic,
 } from 'dedot/contracts';
+import type {
+  StagingXcmV4Location,
+  XcmV3OriginKind,
+  MinidaoCommonCrossChainProposalInstructionLike,
+  StagingXcmV4ResponseLike,
+  MinidaoCommonCrossChainFeeTable,
+  SpWeightsWeightV2Weight,
+} from './types';
 
 export interface ContractTx<ChainApi extends GenericSubstrateApi> extends GenericContractTx<ChainApi> {
   /**
@@ -37,10 +45,14 @@ export interface ContractTx<ChainApi extends GenericSubstrateApi> extends Generi
   /**
    *
    * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location} dest
    * @param {BytesLike} encodedExtrinsic
+   * @param {XcmV3OriginKind} originKind
+   * @param {StagingXcmV4Location | undefined} feeAsset
    * @param {bigint} feeMax
    * @param {bigint} refTime
    * @param {bigint} proofSize
+   * @param {string | undefined} label
    * @param {ContractTxOptions} options
    *
    * @selector 0x547cc8db
@@ -49,10 +61,14 @@ export interface ContractTx<ChainApi extends GenericSubstrateApi> extends Generi
     ChainApi,
     (
       voter: AccountId32Like,
+      dest: StagingXcmV4Location,
       encodedExtrinsic: BytesLike,
+      originKind: XcmV3OriginKind,
+      feeAsset: StagingXcmV4Location | undefined,
       feeMax: bigint,
       refTime: bigint,
       proofSize: bigint,
+      label: string | undefined,
       options: ContractTxOptions,
     ) => ContractSubmittableExtrinsic<ChainApi>
   >;
@@ -60,22 +76,122 @@ export interface ContractTx<ChainApi extends GenericSubstrateApi> extends Generi
   /**
    *
    * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location} dest
+   * @param {Array<MinidaoCommonCrossChainProposalInstructionLike>} program
+   * @param {string | undefined} label
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x4df5932a
+   **/
+  createSuperdaoXcmProposal: GenericContractTxCall<
+    ChainApi,
+    (
+      voter: AccountId32Like,
+      dest: StagingXcmV4Location,
+      program: Array<MinidaoCommonCrossChainProposalInstructionLike>,
+      label: string | undefined,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location} destChain
+   * @param {StagingXcmV4Location} beneficiary
+   * @param {StagingXcmV4Location} asset
+   * @param {bigint} amount
+   * @param {bigint} feeMax
+   * @param {string | undefined} label
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x3de65d46
+   **/
+  createSuperdaoTransferProposal: GenericContractTxCall<
+    ChainApi,
+    (
+      voter: AccountId32Like,
+      destChain: StagingXcmV4Location,
+      beneficiary: StagingXcmV4Location,
+      asset: StagingXcmV4Location,
+      amount: bigint,
+      feeMax: bigint,
+      label: string | undefined,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like} voter
+   * @param {AccountId32Like} callee
+   * @param {FixedBytes<4>} selector
+   * @param {BytesLike} input
+   * @param {bigint} transferredValue
+   * @param {bigint} refTimeLimit
+   * @param {boolean} allowReentry
    * @param {ContractTxOptions} options
    *
    * @selector 0x0071768f
    **/
   createContractCallProposal: GenericContractTxCall<
     ChainApi,
-    (voter: AccountId32Like, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+    (
+      voter: AccountId32Like,
+      callee: AccountId32Like,
+      selector: FixedBytes<4>,
+      input: BytesLike,
+      transferredValue: bigint,
+      refTimeLimit: bigint,
+      allowReentry: boolean,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
   >;
 
   /**
    *
+   * @param {bigint} budget
    * @param {ContractTxOptions} options
    *
-   * @selector 0x9bcaeb73
+   * @selector 0x61998a69
    **/
-  updateValue: GenericContractTxCall<ChainApi, (options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>>;
+  setCallValueBudget: GenericContractTxCall<
+    ChainApi,
+    (budget: bigint, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x2d10c9bd
+   **/
+  deposit: GenericContractTxCall<ChainApi, (options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>>;
+
+  /**
+   *
+   * @param {AccountId32Like} beneficiary
+   * @param {bigint} amount
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x975bccdc
+   **/
+  spend: GenericContractTxCall<
+    ChainApi,
+    (beneficiary: AccountId32Like, amount: bigint, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {[bigint, number] | undefined} limit
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0xcd7a60b2
+   **/
+  setSpendingLimit: GenericContractTxCall<
+    ChainApi,
+    (limit: [bigint, number] | undefined, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
 
   /**
    *
@@ -103,4 +219,180 @@ export interface ContractTx<ChainApi extends GenericSubstrateApi> extends Generi
       options: ContractTxOptions,
     ) => ContractSubmittableExtrinsic<ChainApi>
   >;
+
+  /**
+   *
+   * @param {AccountId32Like} voter
+   * @param {StagingXcmV4Location | undefined} dest
+   * @param {Array<MinidaoCommonCrossChainProposalInstructionLike>} program
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x4fc1648d
+   **/
+  createDaoXcmProposal: GenericContractTxCall<
+    ChainApi,
+    (
+      voter: AccountId32Like,
+      dest: StagingXcmV4Location | undefined,
+      program: Array<MinidaoCommonCrossChainProposalInstructionLike>,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {boolean} vote
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x61e73f73
+   **/
+  voteDaoProposal: GenericContractTxCall<
+    ChainApi,
+    (proposalId: number, vote: boolean, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {number} proposalId
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0xd3666975
+   **/
+  executeDaoProposal: GenericContractTxCall<
+    ChainApi,
+    (proposalId: number, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {bigint} queryId
+   * @param {StagingXcmV4ResponseLike} response
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x4ea77907
+   **/
+  recordQueryResponse: GenericContractTxCall<
+    ChainApi,
+    (
+      queryId: bigint,
+      response: StagingXcmV4ResponseLike,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {AccountId32Like | undefined} responder
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0xeb09e686
+   **/
+  setQueryResponder: GenericContractTxCall<
+    ChainApi,
+    (responder: AccountId32Like | undefined, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {StagingXcmV4Location} feeAsset
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x9321a7c5
+   **/
+  allowDestination: GenericContractTxCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      feeAsset: StagingXcmV4Location,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x58b9b7a1
+   **/
+  disallowDestination: GenericContractTxCall<
+    ChainApi,
+    (dest: StagingXcmV4Location, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {MinidaoCommonCrossChainFeeTable} fees
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x1a2f0076
+   **/
+  setFeeTable: GenericContractTxCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      fees: MinidaoCommonCrossChainFeeTable,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {Array<[number, number]> | undefined} allowedCalls
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0xe1907e65
+   **/
+  setAllowedCalls: GenericContractTxCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      allowedCalls: Array<[number, number]> | undefined,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {StagingXcmV4Location} dest
+   * @param {Array<XcmV3OriginKind>} originKinds
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x99ffce62
+   **/
+  setAllowedOriginKinds: GenericContractTxCall<
+    ChainApi,
+    (
+      dest: StagingXcmV4Location,
+      originKinds: Array<XcmV3OriginKind>,
+      options: ContractTxOptions,
+    ) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {SpWeightsWeightV2Weight} weight
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x9e568f66
+   **/
+  setLocalWeightLimit: GenericContractTxCall<
+    ChainApi,
+    (weight: SpWeightsWeightV2Weight, options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
+
+  /**
+   *
+   * @param {ContractTxOptions} options
+   *
+   * @selector 0x69c98218
+   **/
+  superdaoExecutableUpdateValue: GenericContractTxCall<
+    ChainApi,
+    (options: ContractTxOptions) => ContractSubmittableExtrinsic<ChainApi>
+  >;
 }
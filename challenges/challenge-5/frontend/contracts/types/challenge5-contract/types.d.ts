@@ -1,9 +1,98 @@
 // Generated by dedot cli
 
-import type { AccountId32 } from 'dedot/codecs';
+import type { AccountId32, FixedArray, FixedBytes, Bytes, BytesLike, AccountId32Like } from 'dedot/codecs';
 
 export type InkStorageLazyVecStorageVec = { len: InkStorageLazy; elements: InkStorageLazyMapping };
 
+export type MinidaoCommonCrossChainStateCrossChainState = {
+  destinations: InkStorageLazyMapping;
+  proposals: InkStorageLazyMapping;
+  queries: InkStorageLazyMapping;
+  nextQueryId: bigint;
+  queryResponder?: AccountId32 | undefined;
+  localProposals: InkStorageLazyMapping;
+  nextLocalProposalId: number;
+  localConfig: MinidaoCommonCrossChainDestinationConfig;
+};
+
+export type MinidaoCommonCrossChainDestinationConfig = {
+  feeAsset: StagingXcmV4Location;
+  fees: MinidaoCommonCrossChainFeeTable;
+  allowedCalls?: Array<[number, number]> | undefined;
+  allowedOriginKinds: Array<XcmV3OriginKind>;
+};
+
+export type StagingXcmV4Location = { parents: number; interior: StagingXcmV4Junctions };
+
+export type StagingXcmV4Junctions =
+  | { type: 'Here' }
+  | { type: 'X1'; value: FixedArray<StagingXcmV4Junction, 1> }
+  | { type: 'X2'; value: FixedArray<StagingXcmV4Junction, 2> }
+  | { type: 'X3'; value: FixedArray<StagingXcmV4Junction, 3> }
+  | { type: 'X4'; value: FixedArray<StagingXcmV4Junction, 4> }
+  | { type: 'X5'; value: FixedArray<StagingXcmV4Junction, 5> }
+  | { type: 'X6'; value: FixedArray<StagingXcmV4Junction, 6> }
+  | { type: 'X7'; value: FixedArray<StagingXcmV4Junction, 7> }
+  | { type: 'X8'; value: FixedArray<StagingXcmV4Junction, 8> };
+
+export type StagingXcmV4Junction =
+  | { type: 'Parachain'; value: number }
+  | { type: 'AccountId32'; value: { network?: StagingXcmV4JunctionNetworkId | undefined; id: FixedBytes<32> } }
+  | { type: 'AccountIndex64'; value: { network?: StagingXcmV4JunctionNetworkId | undefined; index: bigint } }
+  | { type: 'AccountKey20'; value: { network?: StagingXcmV4JunctionNetworkId | undefined; key: FixedBytes<20> } }
+  | { type: 'PalletInstance'; value: number }
+  | { type: 'GeneralIndex'; value: bigint }
+  | { type: 'GeneralKey'; value: { length: number; data: FixedBytes<32> } }
+  | { type: 'OnlyChild' }
+  | { type: 'Plurality'; value: { id: XcmV3JunctionBodyId; part: XcmV3JunctionBodyPart } }
+  | { type: 'GlobalConsensus'; value: StagingXcmV4JunctionNetworkId };
+
+export type StagingXcmV4JunctionNetworkId =
+  | { type: 'ByGenesis'; value: FixedBytes<32> }
+  | { type: 'ByFork'; value: { blockNumber: bigint; blockHash: FixedBytes<32> } }
+  | { type: 'Polkadot' }
+  | { type: 'Kusama' }
+  | { type: 'Westend' }
+  | { type: 'Rococo' }
+  | { type: 'Wococo' }
+  | { type: 'Ethereum'; value: { chainId: bigint } }
+  | { type: 'BitcoinCore' }
+  | { type: 'BitcoinCash' }
+  | { type: 'PolkadotBulletin' };
+
+export type XcmV3JunctionBodyId =
+  | { type: 'Unit' }
+  | { type: 'Moniker'; value: FixedBytes<4> }
+  | { type: 'Index'; value: number }
+  | { type: 'Executive' }
+  | { type: 'Technical' }
+  | { type: 'Legislative' }
+  | { type: 'Judicial' }
+  | { type: 'Defense' }
+  | { type: 'Administration' }
+  | { type: 'Treasury' };
+
+export type XcmV3JunctionBodyPart =
+  | { type: 'Voice' }
+  | { type: 'Members'; value: { count: number } }
+  | { type: 'Fraction'; value: { nom: number; denom: number } }
+  | { type: 'AtLeastProportion'; value: { nom: number; denom: number } }
+  | { type: 'MoreThanProportion'; value: { nom: number; denom: number } };
+
+export type MinidaoCommonCrossChainFeeTable = {
+  baseWeight: SpWeightsWeightV2Weight;
+  weightPerByte: SpWeightsWeightV2Weight;
+  baseFee: bigint;
+  feePerSecond: bigint;
+  maxWeight: SpWeightsWeightV2Weight;
+};
+
+export type SpWeightsWeightV2Weight = { refTime: bigint; proofSize: bigint };
+
+export type XcmV3OriginKind = 'Native' | 'SovereignAccount' | 'Superuser' | 'Xcm';
+
+export type MinidaoCommonTreasurySpendingLimit = { cap: bigint; period: number; periodStart: number; spent: bigint };
+
 export type InkStorageTraitsImplsResolverKey = {};
 
 export type InkStorageTraitsImplsAutoKey = {};
@@ -19,6 +108,9 @@ export type Challenge5ContractDao = {
   voters: InkStorageLazyVecStorageVec;
   name: string;
   value: number;
+  crossChain: MinidaoCommonCrossChainStateCrossChainState;
+  callValueBudget: bigint;
+  spendingLimit?: MinidaoCommonTreasurySpendingLimit | undefined;
 };
 
 export type InkPrimitivesLangError = 'CouldNotReadInput';
@@ -30,7 +122,41 @@ export type MinidaoCommonDaoError =
   | { type: 'ProposalDoesNotExist' }
   | { type: 'PrevotePeriodIsNotEnded' }
   | { type: 'NoContractAddress' }
-  | { type: 'SuperdaoError'; value: SuperdaoTraitsError };
+  | { type: 'SuperdaoError'; value: SuperdaoTraitsError }
+  | { type: 'Unauthorized' }
+  | { type: 'UnsupportedDestination' }
+  | { type: 'DestinationNotAllowed' }
+  | { type: 'EmptyXcmProgram' }
+  | { type: 'XcmProgramNotPaid' }
+  | { type: 'NestedXcmProgram' }
+  | { type: 'XcmProgramTooLong' }
+  | { type: 'UnsupportedAsset' }
+  | { type: 'FeeExceedsAmount' }
+  | { type: 'WeightLimitExceeded' }
+  | { type: 'QueryNotFound' }
+  | { type: 'UnexpectedResponse' }
+  | { type: 'ProposalNotPassed' }
+  | { type: 'ProposalAlreadyExecuted' }
+  | { type: 'XcmFailed' }
+  | { type: 'EmptyCall' }
+  | { type: 'CallTooLarge' }
+  | { type: 'CallNotAllowed' }
+  | { type: 'LabelTooLong' }
+  | { type: 'OriginKindNotAllowed' }
+  | { type: 'BudgetExceeded' }
+  | { type: 'InsufficientTreasury' }
+  | { type: 'SpendingCapExceeded' }
+  | { type: 'TreasuryTransferFailed' }
+  | { type: 'PrevotePeriodEnded' }
+  | { type: 'TokenTransferFailed' }
+  | { type: 'InvalidVestingSchedule' }
+  | { type: 'StreamDoesNotExist' }
+  | { type: 'StreamAlreadyCancelled' }
+  | { type: 'NothingToClaim' }
+  | { type: 'TreasuryTokenLimitReached' }
+  | { type: 'InsufficientVotingDeposit' }
+  | { type: 'VotingDepositLocked' }
+  | { type: 'TokenDoesNotExist' };
 
 export type SuperdaoTraitsError =
   | 'DispatchFailed'
@@ -39,4 +165,210 @@ export type SuperdaoTraitsError =
   | 'ProposalNotFound'
   | 'ProposalIsNotApproved';
 
+export type MinidaoCommonCrossChainProposalInstruction =
+  | { type: 'WithdrawAsset'; value: { asset: StagingXcmV4Location; amount: bigint } }
+  | { type: 'BuyExecution'; value: { asset: StagingXcmV4Location; amount: bigint } }
+  | { type: 'Transact'; value: { originKind: XcmV3OriginKind; refTime: bigint; proofSize: bigint; call: Bytes } }
+  | { type: 'DepositAsset'; value: { maxAssets: number; beneficiary: StagingXcmV4Location } }
+  | { type: 'TransferAsset'; value: { asset: StagingXcmV4Location; amount: bigint; beneficiary: StagingXcmV4Location } }
+  | {
+      type: 'ReportTransactStatus';
+      value: { destination: StagingXcmV4Location; queryId: bigint; refTime: bigint; proofSize: bigint };
+    }
+  | {
+      type: 'ReportError';
+      value: { destination: StagingXcmV4Location; queryId: bigint; refTime: bigint; proofSize: bigint };
+    }
+  | {
+      type: 'DepositReserveAsset';
+      value: { maxAssets: number; dest: StagingXcmV4Location; xcm: Array<MinidaoCommonCrossChainProposalInstruction> };
+    }
+  | {
+      type: 'InitiateTeleport';
+      value: { maxAssets: number; dest: StagingXcmV4Location; xcm: Array<MinidaoCommonCrossChainProposalInstruction> };
+    }
+  | { type: 'RefundSurplus' }
+  | { type: 'SetAppendix'; value: Array<MinidaoCommonCrossChainProposalInstruction> }
+  | { type: 'SetErrorHandler'; value: Array<MinidaoCommonCrossChainProposalInstruction> };
+
+export type MinidaoCommonCrossChainProposalInstructionLike =
+  | { type: 'WithdrawAsset'; value: { asset: StagingXcmV4Location; amount: bigint } }
+  | { type: 'BuyExecution'; value: { asset: StagingXcmV4Location; amount: bigint } }
+  | { type: 'Transact'; value: { originKind: XcmV3OriginKind; refTime: bigint; proofSize: bigint; call: BytesLike } }
+  | { type: 'DepositAsset'; value: { maxAssets: number; beneficiary: StagingXcmV4Location } }
+  | { type: 'TransferAsset'; value: { asset: StagingXcmV4Location; amount: bigint; beneficiary: StagingXcmV4Location } }
+  | {
+      type: 'ReportTransactStatus';
+      value: { destination: StagingXcmV4Location; queryId: bigint; refTime: bigint; proofSize: bigint };
+    }
+  | {
+      type: 'ReportError';
+      value: { destination: StagingXcmV4Location; queryId: bigint; refTime: bigint; proofSize: bigint };
+    }
+  | {
+      type: 'DepositReserveAsset';
+      value: {
+        maxAssets: number;
+        dest: StagingXcmV4Location;
+        xcm: Array<MinidaoCommonCrossChainProposalInstructionLike>;
+      };
+    }
+  | {
+      type: 'InitiateTeleport';
+      value: {
+        maxAssets: number;
+        dest: StagingXcmV4Location;
+        xcm: Array<MinidaoCommonCrossChainProposalInstructionLike>;
+      };
+    }
+  | { type: 'RefundSurplus' }
+  | { type: 'SetAppendix'; value: Array<MinidaoCommonCrossChainProposalInstructionLike> }
+  | { type: 'SetErrorHandler'; value: Array<MinidaoCommonCrossChainProposalInstructionLike> };
+
+export type MinidaoCommonCrossChainLocalProposal = {
+  dest?: MinidaoCommonCrossChainChain | undefined;
+  program: Array<MinidaoCommonCrossChainProposalInstruction>;
+  ayes: Array<AccountId32>;
+  nays: Array<AccountId32>;
+  executed: boolean;
+};
+
+export type MinidaoCommonCrossChainLocalProposalLike = {
+  dest?: MinidaoCommonCrossChainChain | undefined;
+  program: Array<MinidaoCommonCrossChainProposalInstructionLike>;
+  ayes: Array<AccountId32Like>;
+  nays: Array<AccountId32Like>;
+  executed: boolean;
+};
+
+export type MinidaoCommonCrossChainChain = { type: 'Relay' } | { type: 'Parachain'; value: number };
+
+export type MinidaoCommonCrossChainForwardedProposal = {
+  label?: string | undefined;
+  dest: MinidaoCommonCrossChainChain;
+  feeAsset: StagingXcmV4Location;
+  feeMax: bigint;
+  weight: SpWeightsWeightV2Weight;
+  callIndex?: [number, number] | undefined;
+  queryId: bigint;
+  status: MinidaoCommonCrossChainExecutionStatus;
+};
+
+export type MinidaoCommonCrossChainExecutionStatus = 'Pending' | 'Succeeded' | 'Failed';
+
+export type MinidaoCommonCrossChainProposalSummary = {
+  label?: string | undefined;
+  dest: StagingXcmV4Location;
+  feeAsset: StagingXcmV4Location;
+  feeMax: bigint;
+  weight: SpWeightsWeightV2Weight;
+  palletIndex?: number | undefined;
+  callIndex?: number | undefined;
+  status: MinidaoCommonCrossChainExecutionStatus;
+};
+
+export type StagingXcmV4Response =
+  | { type: 'Null' }
+  | { type: 'Assets'; value: StagingXcmV4AssetAssets }
+  | { type: 'ExecutionResult'; value?: [number, XcmV3TraitsError] | undefined }
+  | { type: 'Version'; value: number }
+  | { type: 'PalletsInfo'; value: Array<StagingXcmV4PalletInfo> }
+  | { type: 'DispatchResult'; value: XcmV3MaybeErrorCode };
+
+export type StagingXcmV4ResponseLike =
+  | { type: 'Null' }
+  | { type: 'Assets'; value: StagingXcmV4AssetAssets }
+  | { type: 'ExecutionResult'; value?: [number, XcmV3TraitsError] | undefined }
+  | { type: 'Version'; value: number }
+  | { type: 'PalletsInfo'; value: Array<StagingXcmV4PalletInfoLike> }
+  | { type: 'DispatchResult'; value: XcmV3MaybeErrorCodeLike };
+
+export type StagingXcmV4AssetAssets = Array<StagingXcmV4Asset>;
+
+export type StagingXcmV4Asset = { id: StagingXcmV4AssetAssetId; fun: StagingXcmV4AssetFungibility };
+
+export type StagingXcmV4AssetAssetId = StagingXcmV4Location;
+
+export type StagingXcmV4AssetFungibility =
+  | { type: 'Fungible'; value: bigint }
+  | { type: 'NonFungible'; value: StagingXcmV4AssetAssetInstance };
+
+export type StagingXcmV4AssetAssetInstance =
+  | { type: 'Undefined' }
+  | { type: 'Index'; value: bigint }
+  | { type: 'Array4'; value: FixedBytes<4> }
+  | { type: 'Array8'; value: FixedBytes<8> }
+  | { type: 'Array16'; value: FixedBytes<16> }
+  | { type: 'Array32'; value: FixedBytes<32> };
+
+export type XcmV3TraitsError =
+  | { type: 'Overflow' }
+  | { type: 'Unimplemented' }
+  | { type: 'UntrustedReserveLocation' }
+  | { type: 'UntrustedTeleportLocation' }
+  | { type: 'LocationFull' }
+  | { type: 'LocationNotInvertible' }
+  | { type: 'BadOrigin' }
+  | { type: 'InvalidLocation' }
+  | { type: 'AssetNotFound' }
+  | { type: 'FailedToTransactAsset' }
+  | { type: 'NotWithdrawable' }
+  | { type: 'LocationCannotHold' }
+  | { type: 'ExceedsMaxMessageSize' }
+  | { type: 'DestinationUnsupported' }
+  | { type: 'Transport' }
+  | { type: 'Unroutable' }
+  | { type: 'UnknownClaim' }
+  | { type: 'FailedToDecode' }
+  | { type: 'MaxWeightInvalid' }
+  | { type: 'NotHoldingFees' }
+  | { type: 'TooExpensive' }
+  | { type: 'Trap'; value: bigint }
+  | { type: 'ExpectationFalse' }
+  | { type: 'PalletNotFound' }
+  | { type: 'NameMismatch' }
+  | { type: 'VersionIncompatible' }
+  | { type: 'HoldingWouldOverflow' }
+  | { type: 'ExportError' }
+  | { type: 'ReanchorFailed' }
+  | { type: 'NoDeal' }
+  | { type: 'FeesNotMet' }
+  | { type: 'LockError' }
+  | { type: 'NoPermission' }
+  | { type: 'Unanchored' }
+  | { type: 'NotDepositable' }
+  | { type: 'UnhandledXcmVersion' }
+  | { type: 'WeightLimitReached'; value: SpWeightsWeightV2Weight }
+  | { type: 'Barrier' }
+  | { type: 'WeightNotComputable' }
+  | { type: 'ExceedsStackLimit' };
+
+export type StagingXcmV4PalletInfo = {
+  index: number;
+  name: Bytes;
+  moduleName: Bytes;
+  major: number;
+  minor: number;
+  patch: number;
+};
+
+export type StagingXcmV4PalletInfoLike = {
+  index: number;
+  name: BytesLike;
+  moduleName: BytesLike;
+  major: number;
+  minor: number;
+  patch: number;
+};
+
+export type XcmV3MaybeErrorCode =
+  | { type: 'Success' }
+  | { type: 'Error'; value: Bytes }
+  | { type: 'TruncatedError'; value: Bytes };
+
+export type XcmV3MaybeErrorCodeLike =
+  | { type: 'Success' }
+  | { type: 'Error'; value: BytesLike }
+  | { type: 'TruncatedError'; value: BytesLike };
+
 export type InkEnvNoChainExtension = null;
@@ -1,10 +1,11 @@
-import {Box, Button, Flex, Heading, Input, Spinner, Text} from '@chakra-ui/react';
+import {Box, Button, Flex, FormControl, FormLabel, Heading, Input, Select, Spinner, Switch, Text} from '@chakra-ui/react';
 import React, {useState} from 'react';
 import { Proposal } from '@/components/minidao/Proposal.tsx';
 import { useApp } from '@/providers/AppProvider.tsx';
 import { txToaster } from '@/utils/txToaster.tsx';
 import { useContractTx } from 'typink';
 import { useWatchContractQuery } from 'typink/hooks/useContractQuery.ts';
+import { StagingXcmV4Location, XcmV3OriginKind } from 'contracts/types/challenge5-contract';
 
 interface ProposalsPanelProps {
     address: string;
@@ -15,6 +16,16 @@ export function ProposalsPanel({ address }: ProposalsPanelProps) {
     const { superdaoContract: superContract } = useApp();
 
     const [encodedExtrinsic, setEncodedExtrinsic] = useState<string>('');
+    const [destParaId, setDestParaId] = useState<string>('1000');
+    const [originKind, setOriginKind] = useState<XcmV3OriginKind>('SovereignAccount');
+    const [payWithRelayToken, setPayWithRelayToken] = useState<boolean>(false);
+    const [label, setLabel] = useState<string>('');
+    const [callee, setCallee] = useState<string>('');
+    const [selector, setSelector] = useState<string>('');
+    const [callInput, setCallInput] = useState<string>('');
+    const [callValue, setCallValue] = useState<string>('0');
+    const [callRefTime, setCallRefTime] = useState<string>('0');
+    const [allowReentry, setAllowReentry] = useState<boolean>(false);
     const createCrossChainProposalTx = useContractTx(contract, 'createSuperdaoCrossChainProposal');
     const createContractCallProposalTx = useContractTx(contract, 'createContractCallProposal');
     const { data: proposals, isLoading } = useWatchContractQuery({
@@ -30,12 +41,31 @@ export function ProposalsPanel({ address }: ProposalsPanelProps) {
         setEncodedExtrinsic(event.target.value);
     };
 
+    // An empty parachain id targets the relay chain.
+    const dest: StagingXcmV4Location = {
+        parents: 1,
+        interior: destParaId
+            ? { type: 'X1', value: [{ type: 'Parachain', value: Number(destParaId) }] }
+            : { type: 'Here' },
+    };
+    const relayToken: StagingXcmV4Location = { parents: 1, interior: { type: 'Here' } };
+
     const doCreateProposal = async () => {
         const toaster = txToaster('Signing transaction...');
         try {
 
             await createCrossChainProposalTx.signAndSend({
-                args: [address, encodedExtrinsic, feeMax, refTime, proofSize],
+                args: [
+                    address,
+                    dest,
+                    encodedExtrinsic,
+                    originKind,
+                    payWithRelayToken ? relayToken : undefined,
+                    feeMax,
+                    refTime,
+                    proofSize,
+                    label || undefined,
+                ],
                 callback: ({ status }) => {
                     toaster.updateTxStatus(status);
                 },
@@ -50,7 +80,15 @@ export function ProposalsPanel({ address }: ProposalsPanelProps) {
         const toaster = txToaster('Signing transaction...');
         try {
             await createContractCallProposalTx.signAndSend({
-                args: [address],
+                args: [
+                    address,
+                    callee,
+                    selector as `0x${string}`,
+                    callInput || '0x',
+                    BigInt(callValue || 0),
+                    BigInt(callRefTime || 0),
+                    allowReentry,
+                ],
                 callback: ({ status }) => {
                     toaster.updateTxStatus(status);
                 },
@@ -68,17 +106,100 @@ export function ProposalsPanel({ address }: ProposalsPanelProps) {
 
                 <Input
                     type='number'
+                    value={destParaId}
+                    onChange={(event) => setDestParaId(event.target.value)}
+                    placeholder='Enter destination parachain id, empty for the relay chain'
+                    mt={2}
+                />
+
+                <Input
                     value={encodedExtrinsic}
                     onChange={handleInputChange}
                     placeholder='Enter encoded extrinsic'
                     mt={2}
                 />
 
+                <Select
+                    value={originKind}
+                    onChange={(event) => setOriginKind(event.target.value as XcmV3OriginKind)}
+                    mt={2}>
+                    <option value='SovereignAccount'>Sovereign account</option>
+                    <option value='Native'>Native</option>
+                    <option value='Superuser'>Superuser</option>
+                    <option value='Xcm'>Xcm</option>
+                </Select>
+
+                <FormControl display='flex' alignItems='center' mt={2}>
+                    <FormLabel htmlFor='pay-with-relay-token' mb={0}>
+                        Pay fees with the relay chain token instead of the destination's default
+                    </FormLabel>
+                    <Switch
+                        id='pay-with-relay-token'
+                        isChecked={payWithRelayToken}
+                        onChange={(event) => setPayWithRelayToken(event.target.checked)}
+                    />
+                </FormControl>
+
+                <Input
+                    value={label}
+                    onChange={(event) => setLabel(event.target.value)}
+                    placeholder='Enter a label for voters (optional)'
+                    mt={2}
+                />
+
                 <Button mt={4} size='sm' onClick={doCreateProposal}
                         isLoading={createCrossChainProposalTx.inBestBlockProgress}>
                     Create Cross Chain Proposal
                 </Button>
 
+                <Input
+                    value={callee}
+                    onChange={(event) => setCallee(event.target.value)}
+                    placeholder='Enter callee contract address'
+                    mt={2}
+                />
+
+                <Input
+                    value={selector}
+                    onChange={(event) => setSelector(event.target.value)}
+                    placeholder='Enter message selector, e.g. 0x69c98218'
+                    mt={2}
+                />
+
+                <Input
+                    value={callInput}
+                    onChange={(event) => setCallInput(event.target.value)}
+                    placeholder='Enter SCALE-encoded message arguments'
+                    mt={2}
+                />
+
+                <Input
+                    type='number'
+                    value={callValue}
+                    onChange={(event) => setCallValue(event.target.value)}
+                    placeholder='Enter value to transfer'
+                    mt={2}
+                />
+
+                <Input
+                    type='number'
+                    value={callRefTime}
+                    onChange={(event) => setCallRefTime(event.target.value)}
+                    placeholder='Enter ref-time limit, 0 for no limit'
+                    mt={2}
+                />
+
+                <FormControl display='flex' alignItems='center' mt={2}>
+                    <FormLabel htmlFor='allow-reentry' mb={0}>
+                        Allow reentry
+                    </FormLabel>
+                    <Switch
+                        id='allow-reentry'
+                        isChecked={allowReentry}
+                        onChange={(event) => setAllowReentry(event.target.checked)}
+                    />
+                </FormControl>
+
                 <Button
                     mt={4}
                     size='sm'
@@ -94,11 +215,51 @@ export function ProposalsPanel({ address }: ProposalsPanelProps) {
                 {proposals && (
                     <Flex direction='column' gap={2}>
                         {proposals.map(([index, p], idx) => (
-                            <Proposal proposal={p} index={index} key={idx}  address={address}/>
+                            <Box key={idx}>
+                                <Proposal proposal={p} index={index} address={address}/>
+                                <ForwardedProposalSummary index={index} />
+                            </Box>
                         ))}
                     </Flex>
                 )}
             </Box>
         </Box>
     );
-}
\ No newline at end of file
+}
+
+interface ForwardedProposalSummaryProps {
+    index: number;
+}
+
+// Shows the summary the Dao recorded for a proposal it forwarded to the Superdao. Proposals
+// created by other members have no summary.
+function ForwardedProposalSummary({ index }: ForwardedProposalSummaryProps) {
+    const { minidaoContract: contract } = useApp();
+    const { data: summary } = useWatchContractQuery({ contract, fn: 'describeProposal', args: [index] });
+
+    if (!summary?.isOk) return null;
+    const { label, dest, feeMax, weight, palletIndex, callIndex, status } = summary.value;
+    const call = palletIndex === undefined ? 'Unknown call' : `Call ${palletIndex}.${callIndex ?? '?'}`;
+
+    return (
+        <Box borderX='1px' borderBottom='1px' borderColor='gray.200' px={4} py={2} fontSize='sm'>
+            <Text>
+                <b>{label || 'Unlabelled proposal'}</b> on {describeLocation(dest)}
+            </Text>
+            <Text>
+                {call}, paying up to {feeMax.toString()} in fees for {weight.refTime.toString()} ref time
+            </Text>
+            <Text>
+                Execution status: <b>{status}</b>
+            </Text>
+        </Box>
+    );
+}
+
+function describeLocation(location: StagingXcmV4Location): string {
+    const { interior } = location;
+    if (interior.type === 'X1' && interior.value[0].type === 'Parachain') {
+        return `parachain ${interior.value[0].value}`;
+    }
+    return interior.type === 'Here' ? 'the relay chain' : 'an unsupported location';
+}
@@ -12,6 +12,9 @@
 // - **Submission Guidelines:** Verify with R0GUE DevRel, post on X with GitHub link
 // - **Prize:** Pop ring candy
 
+#[cfg(test)]
+mod tests;
+
 #[ink::contract]
 mod dao {
     use ink::{
@@ -21,7 +24,7 @@ mod dao {
         xcm::prelude::*,
     };
     use minidao_common::*;
-    use pop_api::v0::fungibles::traits::Psp22;
+    use pop_api::{primitives::TokenId, v0::fungibles as api};
     use superdao_traits::{Call, ChainCall, ContractCall, SuperDao, Vote};
 
     #[derive(Clone, Default)]
@@ -36,19 +39,99 @@ mod dao {
         pub nay_votes: Vec<(AccountId, Balance)>,
     }
 
+    impl Prevote {
+        fn has_voted(&self, voter: AccountId) -> bool {
+            self.aye_votes.iter().chain(self.nay_votes.iter()).any(|(v, _)| *v == voter)
+        }
+
+        fn is_approved(&self) -> bool {
+            let ayes: Balance = self.aye_votes.iter().map(|(_, b)| b).sum();
+            let nays: Balance = self.nay_votes.iter().map(|(_, b)| b).sum();
+            ayes > nays
+        }
+    }
+
+    #[derive(Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(Debug, PartialEq, Eq, ink::storage::traits::StorageLayout)
+    )]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub enum TreasuryAction {
+        // Transfer `amount` of the fungible `token` to `beneficiary`.
+        Transfer { token: TokenId, beneficiary: AccountId, amount: Balance },
+        // Start paying `beneficiary` according to `schedule`, in native balance if `token` is `None`.
+        StartStream { token: Option<TokenId>, beneficiary: AccountId, schedule: VestingSchedule },
+        // Stop vesting the stream; the amount already vested stays claimable.
+        CancelStream(u32),
+        // Report the Dao's balance of the fungible `token` in `treasury_token_balances`.
+        TrackToken(TokenId),
+    }
+
+    #[derive(Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(Debug, PartialEq, Eq, ink::storage::traits::StorageLayout)
+    )]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct TreasuryProposal {
+        pub action: TreasuryAction,
+        pub prevote: Prevote,
+        pub executed: bool,
+    }
+
+    #[derive(Clone)]
+    #[cfg_attr(
+        feature = "std",
+        derive(Debug, PartialEq, Eq, ink::storage::traits::StorageLayout)
+    )]
+    #[ink::scale_derive(Encode, Decode, TypeInfo)]
+    pub struct Stream {
+        pub token: Option<TokenId>,
+        pub beneficiary: AccountId,
+        pub schedule: VestingSchedule,
+        pub claimed: Balance,
+        pub cancelled_at: Option<BlockNumber>,
+    }
+
+    impl Stream {
+        fn claimable(&self, now: BlockNumber) -> Balance {
+            let until = self.cancelled_at.map_or(now, |cancelled_at| now.min(cancelled_at));
+            self.schedule.vested_at(until).saturating_sub(self.claimed)
+        }
+    }
+
+    // Number of blocks a treasury proposal stays open for prevotes.
+    const PREVOTE_PERIOD: BlockNumber = 100;
+
+    // Maximum number of tokens reported by `treasury_token_balances`, including the governance token.
+    const MAX_TREASURY_TOKENS: u32 = 16;
+
     #[ink(storage)]
     pub struct Dao {
         name: String,
         prevotes: Mapping<u32, Prevote>,
         voters: StorageVec<AccountId>,
-        token: AccountId,
+        token: TokenId,
         superdao: contract_ref!(SuperDao),
+        treasury_tokens: StorageVec<TokenId>,
+        treasury_proposals: Mapping<u32, TreasuryProposal>,
+        next_treasury_proposal_id: u32,
+        streams: Mapping<u32, Stream>,
+        beneficiary_streams: Mapping<AccountId, Vec<u32>>,
+        next_stream_id: u32,
+        // Amount of each token, or of native balance for `None`, that streams still owe.
+        stream_commitments: Mapping<Option<TokenId>, Balance>,
+        voting_deposits: Mapping<AccountId, Balance>,
+        // Last prevote deadline each voting deposit was counted in.
+        voting_locks: Mapping<AccountId, BlockNumber>,
+        total_voting_deposits: Balance,
     }
 
     impl Dao {
         // Constructor that initializes the values for the contract.
         #[ink(constructor)]
-        pub fn new(name: String, superdao: AccountId, token: AccountId) -> Self {
+        pub fn new(name: String, superdao: AccountId, token: TokenId) -> Self {
             // Register your Dao as a member of the Superdao.
             let mut instance = Self {
                 name,
@@ -56,20 +139,36 @@ mod dao {
                 superdao: superdao.into(),
                 voters: StorageVec::new(),
                 prevotes: Mapping::new(),
+                treasury_tokens: StorageVec::new(),
+                treasury_proposals: Mapping::new(),
+                next_treasury_proposal_id: 0,
+                streams: Mapping::new(),
+                beneficiary_streams: Mapping::new(),
+                next_stream_id: 0,
+                stream_commitments: Mapping::new(),
+                voting_deposits: Mapping::new(),
+                voting_locks: Mapping::new(),
+                total_voting_deposits: 0,
             };
+            instance.treasury_tokens.push(&token);
             instance
         }
 
         #[ink(message)]
         pub fn name(&self) -> String {
             // - Returns the name of the Dao
-            todo!()
+            self.name.clone()
         }
 
         #[ink(message)]
         pub fn register_voter(&mut self) -> Result<(), DaoError> {
             // - Error: Throw error `DaoError::VoterAlreadyRegistered` if the voter is registered
             // - Success: Register a new `voter` to the Dao
+            let voter = self.env().caller();
+            if self.has_voter(voter) {
+                return Err(DaoError::VoterAlreadyRegistered);
+            }
+            self.voters.push(&voter);
             Ok(())
         }
 
@@ -77,12 +176,32 @@ mod dao {
         pub fn deregister_voter(&mut self) -> Result<(), DaoError> {
             // - Error: Throw error `DaoError::VoterNotRegistered` if the voter is not registered
             // - Success: Deregister a new `voter` from the Dao
+            let voter = self.env().caller();
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+            let mut new_voters = StorageVec::new();
+            while let Some(stored_voter) = self.voters.pop() {
+                if stored_voter != voter {
+                    new_voters.push(&stored_voter)
+                }
+            }
+            self.voters = new_voters;
             Ok(())
         }
 
         #[ink(message)]
         pub fn has_voter(&self, voter: AccountId) -> bool {
-            todo!()
+            let mut result = false;
+            for i in 0..self.voters.len() {
+                if let Some(v) = self.voters.get(i) {
+                    if v == voter {
+                        result = true;
+                        break;
+                    }
+                }
+            }
+            result
         }
 
         #[ink(message)]
@@ -112,5 +231,379 @@ mod dao {
             // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal does not found.
             Ok(())
         }
+
+        #[ink(message)]
+        pub fn treasury_token_balances(&self) -> Vec<(TokenId, Balance)> {
+            // - Returns the Dao's balance of each tracked fungible token, starting with the governance token.
+            //   Tokens are tracked once the treasury pays them out or a proposal to track them passes.
+            //   Voting deposits and amounts still owed to streams are not part of the treasury.
+            let mut balances = Vec::new();
+            for i in 0..self.treasury_tokens.len() {
+                if let Some(token) = self.treasury_tokens.get(i) {
+                    balances.push((token, self.treasury_balance_of(token)));
+                }
+            }
+            balances
+        }
+
+        #[ink(message)]
+        pub fn create_treasury_proposal(
+            &mut self,
+            token: TokenId,
+            beneficiary: AccountId,
+            amount: Balance,
+        ) -> Result<u32, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the caller is not registered
+            // - Success: Open a prevote on transferring `amount` of the fungible `token` from the treasury to `beneficiary`.
+
+            self.open_treasury_proposal(TreasuryAction::Transfer { token, beneficiary, amount })
+        }
+
+        #[ink(message)]
+        pub fn create_stream_proposal(
+            &mut self,
+            token: Option<TokenId>,
+            beneficiary: AccountId,
+            schedule: VestingSchedule,
+        ) -> Result<u32, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the caller is not registered
+            // - Error: Throw error `DaoError::InvalidVestingSchedule` if the cliff falls after the end of the schedule
+            // - Success: Open a prevote on paying `beneficiary` from the treasury according to `schedule`, in
+            //   native balance if `token` is `None` or in the fungible `token` otherwise.
+
+            schedule.validate()?;
+            self.open_treasury_proposal(TreasuryAction::StartStream { token, beneficiary, schedule })
+        }
+
+        #[ink(message)]
+        pub fn create_cancel_stream_proposal(&mut self, stream_id: u32) -> Result<u32, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the caller is not registered
+            // - Error: Throw error `DaoError::StreamDoesNotExist` if the stream does not exist
+            // - Success: Open a prevote on cancelling the stream.
+
+            if !self.streams.contains(stream_id) {
+                return Err(DaoError::StreamDoesNotExist);
+            }
+            self.open_treasury_proposal(TreasuryAction::CancelStream(stream_id))
+        }
+
+        #[ink(message)]
+        pub fn create_track_token_proposal(&mut self, token: TokenId) -> Result<u32, DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the caller is not registered
+            // - Error: Throw error `DaoError::TokenDoesNotExist` if `token` does not exist
+            // - Error: Throw error `DaoError::TreasuryTokenLimitReached` if the treasury tracks the maximum number of tokens
+            // - Success: Open a prevote on reporting the Dao's balance of the fungible `token` in `treasury_token_balances`.
+
+            if !api::token_exists(token).unwrap_or_default() {
+                return Err(DaoError::TokenDoesNotExist);
+            }
+            if !self.is_treasury_token(token) && self.treasury_tokens.len() >= MAX_TREASURY_TOKENS {
+                return Err(DaoError::TreasuryTokenLimitReached);
+            }
+            self.open_treasury_proposal(TreasuryAction::TrackToken(token))
+        }
+
+        #[ink(message)]
+        pub fn deposit_voting_tokens(&mut self, amount: Balance) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the caller is not registered
+            // - Error: Throw error `DaoError::TokenTransferFailed` if the governance token transfer fails
+            // - Success: Move `amount` of the caller's governance tokens, which the caller must have approved
+            //   the Dao to spend in the runtime, into the Dao and add them to the caller's prevote weight.
+
+            let voter = self.env().caller();
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+            api::transfer_from(self.token, voter, self.env().account_id(), amount)
+                .map_err(|_| DaoError::TokenTransferFailed)?;
+            let deposit = self.voting_deposits.get(voter).unwrap_or_default();
+            self.voting_deposits.insert(voter, &deposit.saturating_add(amount));
+            self.total_voting_deposits = self.total_voting_deposits.saturating_add(amount);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn withdraw_voting_tokens(&mut self, amount: Balance) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::VotingDepositLocked` if a prevote counting the deposit is still open
+            // - Error: Throw error `DaoError::InsufficientVotingDeposit` if `amount` exceeds the caller's deposit
+            // - Error: Throw error `DaoError::TokenTransferFailed` if the governance token transfer fails
+            // - Success: Return `amount` of the caller's deposited governance tokens.
+
+            let voter = self.env().caller();
+            if self.voting_locks.get(voter).is_some_and(|deadline| self.env().block_number() <= deadline) {
+                return Err(DaoError::VotingDepositLocked);
+            }
+            let deposit = self.voting_deposits.get(voter).unwrap_or_default();
+            if amount > deposit {
+                return Err(DaoError::InsufficientVotingDeposit);
+            }
+            self.voting_deposits.insert(voter, &(deposit - amount));
+            self.total_voting_deposits = self.total_voting_deposits.saturating_sub(amount);
+            api::transfer(self.token, voter, amount).map_err(|_| DaoError::TokenTransferFailed)
+        }
+
+        #[ink(message)]
+        pub fn voting_deposit(&self, voter: AccountId) -> Balance {
+            // - Returns the governance tokens `voter` deposited to weigh their prevotes.
+            self.voting_deposits.get(voter).unwrap_or_default()
+        }
+
+        #[ink(message)]
+        pub fn voting_deposit_locked_until(&self, voter: AccountId) -> Option<BlockNumber> {
+            // - Returns the deadline of the last prevote counting the deposit of `voter`, if any.
+            self.voting_locks.get(voter)
+        }
+
+        #[ink(message)]
+        pub fn submit_treasury_prevote(&mut self, proposal_id: u32, approved: bool) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::VoterNotRegistered` if the voter is not registered
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal does not found.
+            // - Error: Throw error `DaoError::PrevotePeriodEnded` if the deadline has passed
+            // - Error: Throw error `DaoError::VoterAlreadyVoted` if the voter already voted
+            // - Error: Throw error `DaoError::InsufficientVotingDeposit` if the voter has no voting deposit
+            // - Success: Record the prevote weighted by the voter's deposited governance tokens, which stay
+            //   locked until the prevote's deadline so that they cannot be moved and counted twice.
+
+            let voter = self.env().caller();
+            if !self.has_voter(voter) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+            let mut proposal =
+                self.treasury_proposals.get(proposal_id).ok_or(DaoError::ProposalDoesNotExist)?;
+            if self.env().block_number() > proposal.prevote.deadline {
+                return Err(DaoError::PrevotePeriodEnded);
+            }
+            if proposal.prevote.has_voted(voter) {
+                return Err(DaoError::VoterAlreadyVoted);
+            }
+            let weight = self.voting_deposits.get(voter).unwrap_or_default();
+            if weight == 0 {
+                return Err(DaoError::InsufficientVotingDeposit);
+            }
+            let deadline = proposal.prevote.deadline;
+            if !self.voting_locks.get(voter).is_some_and(|locked_until| locked_until >= deadline) {
+                self.voting_locks.insert(voter, &deadline);
+            }
+            if approved {
+                proposal.prevote.aye_votes.push((voter, weight));
+            } else {
+                proposal.prevote.nay_votes.push((voter, weight));
+            }
+            self.treasury_proposals.insert(proposal_id, &proposal);
+            Ok(())
+        }
+
+        #[ink(message)]
+        pub fn execute_treasury_proposal(&mut self, proposal_id: u32) -> Result<(), DaoError> {
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal does not found.
+            // - Error: Throw error `DaoError::PrevotePeriodIsNotEnded` if the deadline has not passed
+            // - Error: Throw error `DaoError::ProposalAlreadyExecuted` if the transfer was already made
+            // - Error: Throw error `DaoError::ProposalNotPassed` if ayes do not outweigh nays
+            // - Error: Throw error `DaoError::InsufficientTreasury` if the treasury holds too little of the token,
+            //   not counting voting deposits and amounts still owed to streams
+            // - Error: Throw error `DaoError::TokenTransferFailed` if the token transfer fails
+            // - Error: Throw error `DaoError::StreamAlreadyCancelled` if the stream was already cancelled
+            // - Error: Throw error `DaoError::TreasuryTokenLimitReached` if the treasury tracks the maximum number of tokens
+            // - Success: Perform the proposed treasury action.
+
+            let mut proposal =
+                self.treasury_proposals.get(proposal_id).ok_or(DaoError::ProposalDoesNotExist)?;
+            if self.env().block_number() <= proposal.prevote.deadline {
+                return Err(DaoError::PrevotePeriodIsNotEnded);
+            }
+            if proposal.executed {
+                return Err(DaoError::ProposalAlreadyExecuted);
+            }
+            if !proposal.prevote.is_approved() {
+                return Err(DaoError::ProposalNotPassed);
+            }
+            proposal.executed = true;
+            self.treasury_proposals.insert(proposal_id, &proposal);
+
+            match proposal.action {
+                TreasuryAction::Transfer { token, beneficiary, amount } => {
+                    self.pay(Some(token), beneficiary, amount)
+                }
+                TreasuryAction::StartStream { token, beneficiary, schedule } => {
+                    self.start_stream(token, beneficiary, schedule)
+                }
+                TreasuryAction::CancelStream(stream_id) => self.cancel_stream(stream_id),
+                TreasuryAction::TrackToken(token) => self.track_token(token),
+            }
+        }
+
+        #[ink(message)]
+        pub fn get_treasury_proposal(&self, proposal_id: u32) -> Result<TreasuryProposal, DaoError> {
+            // - Error: Throw error `DaoError::ProposalDoesNotExist` if the proposal does not found.
+            // - Success: Returns the treasury proposal and its prevote.
+            self.treasury_proposals.get(proposal_id).ok_or(DaoError::ProposalDoesNotExist)
+        }
+
+        #[ink(message)]
+        pub fn claim_vested(&mut self) -> Result<Balance, DaoError> {
+            // - Error: Throw error `DaoError::NothingToClaim` if none of the caller's streams has an unclaimed vested amount
+            // - Error: Throw error `DaoError::TreasuryTransferFailed`, `DaoError::InsufficientTreasury` or
+            //   `DaoError::TokenTransferFailed` if a payout fails
+            // - Success: Pay out everything vested so far across the caller's streams and return the total.
+
+            let beneficiary = self.env().caller();
+            let now = self.env().block_number();
+            let mut total: Balance = 0;
+            for stream_id in self.beneficiary_streams.get(beneficiary).unwrap_or_default() {
+                let Some(mut stream) = self.streams.get(stream_id) else {
+                    continue;
+                };
+                let amount = stream.claimable(now);
+                if amount == 0 {
+                    continue;
+                }
+                stream.claimed = stream.claimed.saturating_add(amount);
+                self.streams.insert(stream_id, &stream);
+                self.release_commitment(stream.token, amount);
+                self.pay(stream.token, beneficiary, amount)?;
+                total = total.saturating_add(amount);
+            }
+            if total == 0 {
+                return Err(DaoError::NothingToClaim);
+            }
+            Ok(total)
+        }
+
+        #[ink(message)]
+        pub fn get_stream(&self, stream_id: u32) -> Result<Stream, DaoError> {
+            // - Error: Throw error `DaoError::StreamDoesNotExist` if the stream does not exist
+            // - Success: Returns the stream's schedule, claimed amount and cancellation block.
+            self.streams.get(stream_id).ok_or(DaoError::StreamDoesNotExist)
+        }
+
+        #[ink(message)]
+        pub fn claimable(&self, stream_id: u32) -> Result<Balance, DaoError> {
+            // - Error: Throw error `DaoError::StreamDoesNotExist` if the stream does not exist
+            // - Success: Returns the amount the beneficiary could claim from the stream now.
+            let stream = self.streams.get(stream_id).ok_or(DaoError::StreamDoesNotExist)?;
+            Ok(stream.claimable(self.env().block_number()))
+        }
+
+        #[ink(message)]
+        pub fn streams_of(&self, beneficiary: AccountId) -> Vec<u32> {
+            // - Returns the identifiers of the streams paying `beneficiary`.
+            self.beneficiary_streams.get(beneficiary).unwrap_or_default()
+        }
+
+        fn open_treasury_proposal(&mut self, action: TreasuryAction) -> Result<u32, DaoError> {
+            if !self.has_voter(self.env().caller()) {
+                return Err(DaoError::VoterNotRegistered);
+            }
+            let proposal_id = self.next_treasury_proposal_id;
+            self.next_treasury_proposal_id = proposal_id.wrapping_add(1);
+            let prevote = Prevote {
+                deadline: self.env().block_number().saturating_add(PREVOTE_PERIOD),
+                ..Default::default()
+            };
+            self.treasury_proposals
+                .insert(proposal_id, &TreasuryProposal { action, prevote, executed: false });
+            Ok(proposal_id)
+        }
+
+        fn start_stream(
+            &mut self,
+            token: Option<TokenId>,
+            beneficiary: AccountId,
+            schedule: VestingSchedule,
+        ) -> Result<(), DaoError> {
+            // The whole schedule is set aside so that later payouts cannot leave the stream unfunded.
+            if schedule.total > self.free_balance(token) {
+                return Err(DaoError::InsufficientTreasury);
+            }
+            let committed = self.stream_commitments.get(token).unwrap_or_default();
+            self.stream_commitments.insert(token, &committed.saturating_add(schedule.total));
+            let stream_id = self.next_stream_id;
+            self.next_stream_id = stream_id.wrapping_add(1);
+            self.streams.insert(
+                stream_id,
+                &Stream { token, beneficiary, schedule, claimed: 0, cancelled_at: None },
+            );
+            let mut stream_ids = self.beneficiary_streams.get(beneficiary).unwrap_or_default();
+            stream_ids.push(stream_id);
+            self.beneficiary_streams.insert(beneficiary, &stream_ids);
+            Ok(())
+        }
+
+        fn cancel_stream(&mut self, stream_id: u32) -> Result<(), DaoError> {
+            let mut stream = self.streams.get(stream_id).ok_or(DaoError::StreamDoesNotExist)?;
+            if stream.cancelled_at.is_some() {
+                return Err(DaoError::StreamAlreadyCancelled);
+            }
+            let now = self.env().block_number();
+            stream.cancelled_at = Some(now);
+            self.streams.insert(stream_id, &stream);
+            // The unvested part of the schedule returns to the treasury.
+            let unvested = stream.schedule.total.saturating_sub(stream.schedule.vested_at(now));
+            self.release_commitment(stream.token, unvested);
+            Ok(())
+        }
+
+        fn pay(&mut self, token: Option<TokenId>, beneficiary: AccountId, amount: Balance) -> Result<(), DaoError> {
+            if amount > self.free_balance(token) {
+                return Err(DaoError::InsufficientTreasury);
+            }
+            match token {
+                None => self
+                    .env()
+                    .transfer(beneficiary, amount)
+                    .map_err(|_| DaoError::TreasuryTransferFailed),
+                Some(token) => {
+                    api::transfer(token, beneficiary, amount).map_err(|_| DaoError::TokenTransferFailed)?;
+                    // A full list of tracked tokens must not block payouts.
+                    let _ = self.track_token(token);
+                    Ok(())
+                }
+            }
+        }
+
+        // Balance of the fungible `token` the treasury may spend, leaving out voting deposits and
+        // amounts still owed to streams.
+        fn treasury_balance_of(&self, token: TokenId) -> Balance {
+            let balance = api::balance_of(token, self.env().account_id()).unwrap_or_default();
+            let balance = if token == self.token {
+                balance.saturating_sub(self.total_voting_deposits)
+            } else {
+                balance
+            };
+            balance.saturating_sub(self.stream_commitments.get(Some(token)).unwrap_or_default())
+        }
+
+        // Balance the treasury may spend in the fungible `token`, or in native balance if `token` is `None`.
+        fn free_balance(&self, token: Option<TokenId>) -> Balance {
+            match token {
+                None => self.env().balance().saturating_sub(self.stream_commitments.get(token).unwrap_or_default()),
+                Some(token) => self.treasury_balance_of(token),
+            }
+        }
+
+        fn release_commitment(&mut self, token: Option<TokenId>, amount: Balance) {
+            let committed = self.stream_commitments.get(token).unwrap_or_default();
+            self.stream_commitments.insert(token, &committed.saturating_sub(amount));
+        }
+
+        fn track_token(&mut self, token: TokenId) -> Result<(), DaoError> {
+            if self.is_treasury_token(token) {
+                return Ok(());
+            }
+            if self.treasury_tokens.len() >= MAX_TREASURY_TOKENS {
+                return Err(DaoError::TreasuryTokenLimitReached);
+            }
+            self.treasury_tokens.push(&token);
+            Ok(())
+        }
+
+        fn is_treasury_token(&self, token: TokenId) -> bool {
+            for i in 0..self.treasury_tokens.len() {
+                if self.treasury_tokens.get(i) == Some(token) {
+                    return true;
+                }
+            }
+            false
+        }
     }
 }
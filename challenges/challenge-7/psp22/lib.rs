@@ -1,6 +1,10 @@
 #![cfg_attr(not(feature = "std"), no_std, no_main)]
 
-use ink::prelude::{string::String, vec::Vec};
+use ink::{
+    env::{DefaultEnvironment, Environment},
+    prelude::{string::String, vec::Vec},
+    primitives::AccountId,
+};
 use pop_api::{
     primitives::TokenId,
     v0::fungibles::{
@@ -14,14 +18,255 @@ use pop_api::{
 #[cfg(test)]
 mod tests;
 
+type Balance = <DefaultEnvironment as Environment>::Balance;
+type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
+
+/// Maximum number of entries in a `batch_mint` or `batch_transfer`.
+pub const MAX_BATCH_LEN: u32 = 64;
+
+/// Permissions that can be granted on the token contract.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum Role {
+    /// Manages roles and the contract's own token balance.
+    Admin,
+    /// Mints new tokens.
+    Minter,
+    /// Burns tokens.
+    Burner,
+    /// Pauses and freezes token movements.
+    Pauser,
+}
+
+/// Whose tokens `transfer`, `approve` and the allowance messages act on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub enum Mode {
+    /// Messages act on the contract's own balance and require the `Admin` role.
+    Custodial,
+    /// Messages act on the caller's balance, following standard PSP22 semantics. Allowances
+    /// between accounts are recorded by the contract, and every holder must approve the
+    /// contract in the runtime for the tokens it moves on their behalf.
+    PerUser,
+}
+
+// Mirrors `minidao_common::SpendingLimit`, which this contract cannot depend on: it is built
+// against the ink! 5.0.0 release required by `pop-api`, while the Dao crates track ink! master.
+// Keep the period arithmetic of both in sync.
+
+/// Caps how many tokens a minter may mint within a window of blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct MintAllowance {
+    /// Maximum number of tokens minted per period.
+    pub cap: Balance,
+    /// Length of a period in blocks. A zero-length period never renews.
+    pub period: BlockNumber,
+    /// Block at which the current period started.
+    pub period_start: BlockNumber,
+    /// Number of tokens already minted in the current period.
+    pub minted: Balance,
+}
+
+impl MintAllowance {
+    /// Number of tokens that can still be minted at block `now`.
+    pub fn remaining(&self, now: BlockNumber) -> Balance {
+        if self.is_period_over(now) {
+            self.cap
+        } else {
+            self.cap.saturating_sub(self.minted)
+        }
+    }
+
+    /// Records `value` tokens minted at block `now`, starting a new period if the current one is
+    /// over. Callers must check `remaining` first.
+    fn record(&mut self, now: BlockNumber, value: Balance) {
+        if self.is_period_over(now) {
+            // Periods stay aligned to the block at which the allowance was set.
+            let elapsed = now.saturating_sub(self.period_start);
+            self.period_start = now.saturating_sub(elapsed % self.period);
+            self.minted = 0;
+        }
+        self.minted = self.minted.saturating_add(value);
+    }
+
+    fn is_period_over(&self, now: BlockNumber) -> bool {
+        self.period > 0 && now >= self.period_start.saturating_add(self.period)
+    }
+}
+
+/// Event emitted when a role is granted to an account.
+#[ink::event]
+pub struct RoleGranted {
+    /// The granted role.
+    #[ink(topic)]
+    pub role: Role,
+    /// The account receiving the role.
+    #[ink(topic)]
+    pub account: AccountId,
+    /// The account that granted the role.
+    pub sender: AccountId,
+}
+
+/// Event emitted when a role is revoked from an account.
+#[ink::event]
+pub struct RoleRevoked {
+    /// The revoked role.
+    #[ink(topic)]
+    pub role: Role,
+    /// The account losing the role.
+    #[ink(topic)]
+    pub account: AccountId,
+    /// The account that revoked the role.
+    pub sender: AccountId,
+}
+
+/// Event emitted when token movements through the contract are paused.
+#[ink::event]
+pub struct Paused {
+    /// The account that paused the contract.
+    #[ink(topic)]
+    pub sender: AccountId,
+}
+
+/// Event emitted when token movements through the contract resume.
+#[ink::event]
+pub struct Unpaused {
+    /// The account that unpaused the contract.
+    #[ink(topic)]
+    pub sender: AccountId,
+}
+
+/// Event emitted when token movements to or from an account are frozen.
+#[ink::event]
+pub struct Frozen {
+    /// The frozen account.
+    #[ink(topic)]
+    pub account: AccountId,
+    /// The account that froze it.
+    pub sender: AccountId,
+}
+
+/// Event emitted when token movements to or from an account are allowed again.
+#[ink::event]
+pub struct Thawed {
+    /// The thawed account.
+    #[ink(topic)]
+    pub account: AccountId,
+    /// The account that thawed it.
+    pub sender: AccountId,
+}
+
+/// Event emitted when destruction of the token starts.
+#[ink::event]
+pub struct DestroyStarted {
+    /// The token being destroyed.
+    #[ink(topic)]
+    pub id: TokenId,
+}
+
+/// Event emitted when a batch of the token's accounts is removed during destruction.
+#[ink::event]
+pub struct AccountsDestroyed {
+    /// The token being destroyed.
+    #[ink(topic)]
+    pub id: TokenId,
+}
+
+/// Event emitted when a batch of the token's approvals is removed during destruction.
+#[ink::event]
+pub struct ApprovalsDestroyed {
+    /// The token being destroyed.
+    #[ink(topic)]
+    pub id: TokenId,
+}
+
+/// Event emitted when the token is removed.
+#[ink::event]
+pub struct Destroyed {
+    /// The destroyed token.
+    #[ink(topic)]
+    pub id: TokenId,
+}
+
+/// Event emitted when ownership of the contract changes.
+#[ink::event]
+pub struct OwnershipTransferred {
+    /// The previous owner.
+    #[ink(topic)]
+    pub previous_owner: Option<AccountId>,
+    /// The new owner, or `None` if ownership was renounced.
+    #[ink(topic)]
+    pub new_owner: Option<AccountId>,
+}
+
+/// Calls to Pop's runtime that the Pop API does not expose, dispatched with `call_runtime`.
+#[ink::scale_derive(Encode)]
+enum RuntimeCall {
+    /// Index of `pallet-assets` in Pop's runtime.
+    #[codec(index = 52)]
+    Assets(AssetsCall),
+}
+
+/// Calls of `pallet-assets` that take over an existing token or finish destroying it.
+#[ink::scale_derive(Encode)]
+enum AssetsCall {
+    #[codec(index = 3)]
+    DestroyAccounts {
+        #[codec(compact)]
+        id: TokenId,
+    },
+    #[codec(index = 4)]
+    DestroyApprovals {
+        #[codec(compact)]
+        id: TokenId,
+    },
+    #[codec(index = 5)]
+    FinishDestroy {
+        #[codec(compact)]
+        id: TokenId,
+    },
+    #[codec(index = 15)]
+    TransferOwnership {
+        #[codec(compact)]
+        id: TokenId,
+        owner: MultiAddress,
+    },
+    #[codec(index = 16)]
+    SetTeam {
+        #[codec(compact)]
+        id: TokenId,
+        issuer: MultiAddress,
+        admin: MultiAddress,
+        freezer: MultiAddress,
+    },
+}
+
+/// Account lookup used by Pop's runtime calls.
+#[ink::scale_derive(Encode)]
+enum MultiAddress {
+    Id(AccountId),
+}
+
 #[ink::contract]
 mod fungibles {
     use super::*;
+    use ink::storage::Mapping;
 
     #[ink(storage)]
     pub struct Fungible {
         id: TokenId,
-        owner: AccountId,
+        owner: Option<AccountId>,
+        pending_owner: Option<AccountId>,
+        roles: Mapping<(AccountId, Role), ()>,
+        mode: Mode,
+        allowances: Mapping<(AccountId, AccountId), Balance>,
+        paused: bool,
+        frozen: Mapping<AccountId, ()>,
+        max_supply: Option<Balance>,
+        mint_allowances: Mapping<AccountId, MintAllowance>,
     }
 
     impl Fungible {
@@ -35,10 +280,24 @@ mod fungibles {
         // inactive balances from bloating the blockchain state and slowing down the network.
         #[ink(constructor, payable)]
         pub fn new(id: TokenId, min_balance: Balance) -> Result<Self, Psp22Error> {
-            let instance = Self {
-                id,
-                owner: Self::env().caller(),
-            };
+            Self::with_mode(id, min_balance, Mode::Custodial, None)
+        }
+
+        /// Instantiate the contract in the given `mode` and create a new token.
+        ///
+        /// # Parameters
+        /// * - `id` - The identifier of the token.
+        /// * - `min_balance` - The minimum balance required for accounts holding this token.
+        /// * - `mode` - Whose tokens `transfer`, `approve` and the allowance messages act on.
+        /// * - `max_supply` - The total supply that minting can never exceed, if any.
+        #[ink(constructor, payable)]
+        pub fn with_mode(
+            id: TokenId,
+            min_balance: Balance,
+            mode: Mode,
+            max_supply: Option<Balance>,
+        ) -> Result<Self, Psp22Error> {
+            let instance = Self::init(id, mode, max_supply);
             let contract_id = instance.env().account_id();
             api::create(id, contract_id, min_balance).map_err(Psp22Error::from)?;
             instance.env().emit_event(Created {
@@ -48,6 +307,98 @@ mod fungibles {
             });
             Ok(instance)
         }
+
+        /// Instantiate the contract in the given `mode` for an existing token. The contract must
+        /// already be the owner of the token, and becomes its issuer, admin and freezer.
+        ///
+        /// # Parameters
+        /// * - `id` - The identifier of the token.
+        /// * - `mode` - Whose tokens `transfer`, `approve` and the allowance messages act on.
+        /// * - `max_supply` - The total supply that minting can never exceed, if any.
+        #[ink(constructor, payable)]
+        pub fn from_existing(
+            id: TokenId,
+            mode: Mode,
+            max_supply: Option<Balance>,
+        ) -> Result<Self, Psp22Error> {
+            if !api::token_exists(id).map_err(Psp22Error::from)? {
+                return Err(Psp22Error::Custom(String::from("Token does not exist")));
+            }
+            if let Some(max_supply) = max_supply {
+                if api::total_supply(id).map_err(Psp22Error::from)? > max_supply {
+                    return Err(Psp22Error::Custom(String::from("Max supply exceeded")));
+                }
+            }
+            let instance = Self::init(id, mode, max_supply);
+            instance.take_over_token()?;
+            Ok(instance)
+        }
+
+        /// Instantiate the contract in the given `mode`, create a new token and set its metadata.
+        ///
+        /// # Parameters
+        /// * - `id` - The identifier of the token.
+        /// * - `min_balance` - The minimum balance required for accounts holding this token.
+        /// * - `mode` - Whose tokens `transfer`, `approve` and the allowance messages act on.
+        /// * - `max_supply` - The total supply that minting can never exceed, if any.
+        /// * - `name` - The name of the token.
+        /// * - `symbol` - The symbol of the token.
+        /// * - `decimals` - The number of decimals of the token.
+        #[ink(constructor, payable)]
+        pub fn with_metadata(
+            id: TokenId,
+            min_balance: Balance,
+            mode: Mode,
+            max_supply: Option<Balance>,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Result<Self, Psp22Error> {
+            let instance = Self::with_mode(id, min_balance, mode, max_supply)?;
+            api::set_metadata(id, name.into(), symbol.into(), decimals).map_err(Psp22Error::from)?;
+            Ok(instance)
+        }
+
+        /// Build the contract storage, granting every role to the caller.
+        fn init(id: TokenId, mode: Mode, max_supply: Option<Balance>) -> Self {
+            let owner = Self::env().caller();
+            let mut instance = Self {
+                id,
+                owner: Some(owner),
+                pending_owner: None,
+                roles: Mapping::new(),
+                mode,
+                allowances: Mapping::new(),
+                paused: false,
+                frozen: Mapping::new(),
+                max_supply,
+                mint_allowances: Mapping::new(),
+            };
+            // The owner holds the `Admin` role implicitly and starts with every other role.
+            for role in [Role::Minter, Role::Burner, Role::Pauser] {
+                instance.roles.insert((owner, role), &());
+            }
+            instance
+        }
+
+        /// Check that the contract owns the token and make it the token's issuer, admin and
+        /// freezer, so that it can mint and burn. The Pop API does not expose the token's owner or
+        /// team, so ownership is checked by transferring it to the contract itself, which changes
+        /// nothing but fails for any other owner, and the team is then set by the owner.
+        fn take_over_token(&self) -> Result<(), Psp22Error> {
+            let contract_id = self.env().account_id();
+            self.call_assets(AssetsCall::TransferOwnership {
+                id: self.id,
+                owner: MultiAddress::Id(contract_id),
+            })
+            .map_err(|_| Psp22Error::Custom(String::from("Not the token owner")))?;
+            self.call_assets(AssetsCall::SetTeam {
+                id: self.id,
+                issuer: MultiAddress::Id(contract_id),
+                admin: MultiAddress::Id(contract_id),
+                freezer: MultiAddress::Id(contract_id),
+            })
+        }
     }
 
     impl Psp22 for Fungible {
@@ -66,18 +417,24 @@ mod fungibles {
             api::balance_of(self.id, owner).unwrap_or_default()
         }
 
-        /// Returns the allowance for a `spender` approved by an `owner`.
+        /// Returns the allowance for a `spender` approved by an `owner`. In per-user mode, this is
+        /// the allowance recorded by the contract.
         ///
         /// # Parameters
         /// - `owner` - The account that owns the tokens.
         /// - `spender` - The account that is allowed to spend the tokens.
         #[ink(message)]
         fn allowance(&self, owner: AccountId, spender: AccountId) -> Balance {
-            api::allowance(self.id, owner, spender).unwrap_or_default()
+            match self.mode {
+                Mode::Custodial => api::allowance(self.id, owner, spender).unwrap_or_default(),
+                Mode::PerUser => self.allowances.get((owner, spender)).unwrap_or_default(),
+            }
         }
 
-        /// Transfers `value` amount of tokens from the contract to account `to` with
-        /// additional `data` in unspecified format. Contract must be pre-approved by `from`.
+        /// Transfers `value` amount of tokens to account `to` with additional `data` in
+        /// unspecified format. In custodial mode, tokens are moved from the contract's own balance.
+        /// In per-user mode, tokens are moved from the caller, who must have approved the
+        /// contract in the runtime.
         ///
         /// # Parameters
         /// - `to` - The recipient account.
@@ -90,16 +447,20 @@ mod fungibles {
             value: Balance,
             _data: Vec<u8>,
         ) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
-            let contract = self.env().account_id();
+            let from = self.acting_account()?;
 
-            // No-op if the contract and `to` is the same address or `value` is zero.
-            if contract == to || value == 0 {
+            // No-op if `from` and `to` is the same address or `value` is zero.
+            if from == to || value == 0 {
                 return Ok(());
             }
-            api::transfer(self.id, to, value).map_err(Psp22Error::from)?;
+            self.ensure_movable(&[from, to])?;
+            match self.mode {
+                Mode::Custodial => api::transfer(self.id, to, value),
+                Mode::PerUser => api::transfer_from(self.id, from, to, value),
+            }
+            .map_err(Psp22Error::from)?;
             self.env().emit_event(Transfer {
-                from: Some(contract),
+                from: Some(from),
                 to: Some(to),
                 value,
             });
@@ -107,7 +468,9 @@ mod fungibles {
         }
 
         /// Transfers `value` tokens on behalf of `from` to the account `to`
-        /// with additional `data` in unspecified format. Contract must be pre-approved by `from`.
+        /// with additional `data` in unspecified format. Contract must be pre-approved by `from`
+        /// in the runtime. In per-user mode, the caller must also have been approved by `from`
+        /// through the contract.
         ///
         /// # Parameters
         /// - `from` - The account from which the token balance will be withdrawn.
@@ -122,30 +485,51 @@ mod fungibles {
             value: Balance,
             _data: Vec<u8>,
         ) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
-            let contract = self.env().account_id();
+            let spender = match self.mode {
+                Mode::Custodial => {
+                    self.ensure_role(Role::Admin)?;
+                    self.env().account_id()
+                }
+                Mode::PerUser => self.env().caller(),
+            };
 
             // No-op if `from` and `to` is the same address or `value` is zero.
             if from == to || value == 0 {
                 return Ok(());
             }
-            // A successful transfer reduces the allowance from `from` to the contract and triggers
-            // an `Approval` event with the updated allowance amount.
-            api::transfer_from(self.id, from, to, value).map_err(Psp22Error::from)?;
+            self.ensure_movable(&[from, to])?;
+            let allowance = match self.mode {
+                Mode::Custodial => {
+                    // A successful transfer reduces the allowance from `from` to the contract.
+                    api::transfer_from(self.id, from, to, value).map_err(Psp22Error::from)?;
+                    api::allowance(self.id, from, spender).unwrap_or_default()
+                }
+                Mode::PerUser => {
+                    let allowance = self.allowance(from, spender);
+                    if allowance < value {
+                        return Err(Psp22Error::InsufficientAllowance);
+                    }
+                    api::transfer_from(self.id, from, to, value).map_err(Psp22Error::from)?;
+                    let allowance = allowance - value;
+                    self.allowances.insert((from, spender), &allowance);
+                    allowance
+                }
+            };
             self.env().emit_event(Transfer {
-                from: Some(contract),
+                from: Some(from),
                 to: Some(to),
                 value,
             });
             self.env().emit_event(Approval {
                 owner: from,
-                spender: contract,
-                value: self.allowance(from, contract),
+                spender,
+                value: allowance,
             });
             Ok(())
         }
 
-        /// Approves `spender` to spend `value` amount of tokens on behalf of the contract.
+        /// Approves `spender` to spend `value` amount of tokens on behalf of the contract, or of
+        /// the caller in per-user mode.
         ///
         /// Successive calls of this method overwrite previous values.
         ///
@@ -154,16 +538,22 @@ mod fungibles {
         /// - `value` - The number of tokens to approve.
         #[ink(message)]
         fn approve(&mut self, spender: AccountId, value: Balance) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
-            let contract = self.env().account_id();
+            let owner = self.acting_account()?;
 
-            // No-op if the contract and `spender` is the same address.
-            if contract == spender {
+            // No-op if `owner` and `spender` is the same address.
+            if owner == spender {
                 return Ok(());
             }
-            api::approve(self.id, spender, value).map_err(Psp22Error::from)?;
+            match self.mode {
+                Mode::Custodial => {
+                    api::approve(self.id, spender, value).map_err(Psp22Error::from)?;
+                }
+                Mode::PerUser => {
+                    self.allowances.insert((owner, spender), &value);
+                }
+            }
             self.env().emit_event(Approval {
-                owner: contract,
+                owner,
                 spender,
                 value,
             });
@@ -181,17 +571,25 @@ mod fungibles {
             spender: AccountId,
             value: Balance,
         ) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
-            let contract = self.env().account_id();
+            let owner = self.acting_account()?;
 
-            // No-op if the contract and `spender` is the same address or `value` is zero.
-            if contract == spender || value == 0 {
+            // No-op if `owner` and `spender` is the same address or `value` is zero.
+            if owner == spender || value == 0 {
                 return Ok(());
             }
-            api::increase_allowance(self.id, spender, value).map_err(Psp22Error::from)?;
-            let allowance = self.allowance(contract, spender);
+            let allowance = match self.mode {
+                Mode::Custodial => {
+                    api::increase_allowance(self.id, spender, value).map_err(Psp22Error::from)?;
+                    self.allowance(owner, spender)
+                }
+                Mode::PerUser => {
+                    let allowance = self.allowance(owner, spender).saturating_add(value);
+                    self.allowances.insert((owner, spender), &allowance);
+                    allowance
+                }
+            };
             self.env().emit_event(Approval {
-                owner: contract,
+                owner,
                 spender,
                 value: allowance,
             });
@@ -209,19 +607,30 @@ mod fungibles {
             spender: AccountId,
             value: Balance,
         ) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
-            let contract = self.env().account_id();
+            let owner = self.acting_account()?;
 
-            // No-op if the contract and `spender` is the same address or `value` is zero.
-            if contract == spender || value == 0 {
+            // No-op if `owner` and `spender` is the same address or `value` is zero.
+            if owner == spender || value == 0 {
                 return Ok(());
             }
-            api::decrease_allowance(self.id, spender, value).map_err(Psp22Error::from)?;
-            let value = self.allowance(contract, spender);
+            let allowance = match self.mode {
+                Mode::Custodial => {
+                    api::decrease_allowance(self.id, spender, value).map_err(Psp22Error::from)?;
+                    self.allowance(owner, spender)
+                }
+                Mode::PerUser => {
+                    let allowance = self
+                        .allowance(owner, spender)
+                        .checked_sub(value)
+                        .ok_or(Psp22Error::InsufficientAllowance)?;
+                    self.allowances.insert((owner, spender), &allowance);
+                    allowance
+                }
+            };
             self.env().emit_event(Approval {
-                owner: contract,
+                owner,
                 spender,
-                value,
+                value: allowance,
             });
             Ok(())
         }
@@ -260,11 +669,26 @@ mod fungibles {
         /// - `value` - The number of tokens to mint.
         #[ink(message)]
         fn mint(&mut self, account: AccountId, value: Balance) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
+            self.ensure_role(Role::Minter)?;
             // No-op if `value` is zero.
             if value == 0 {
                 return Ok(());
             }
+            self.ensure_movable(&[account])?;
+            if let Some(max_supply) = self.max_supply {
+                if self.total_supply().saturating_add(value) > max_supply {
+                    return Err(Psp22Error::Custom(String::from("Max supply exceeded")));
+                }
+            }
+            let minter = self.env().caller();
+            if let Some(mut allowance) = self.mint_allowances.get(minter) {
+                let now = self.env().block_number();
+                if value > allowance.remaining(now) {
+                    return Err(Psp22Error::Custom(String::from("Mint allowance exceeded")));
+                }
+                allowance.record(now, value);
+                self.mint_allowances.insert(minter, &allowance);
+            }
             api::mint(self.id, account, value).map_err(Psp22Error::from)?;
             self.env().emit_event(Transfer {
                 from: None,
@@ -283,11 +707,12 @@ mod fungibles {
         /// - `value` - The number of tokens to destroy.
         #[ink(message)]
         fn burn(&mut self, account: AccountId, value: Balance) -> Result<(), Psp22Error> {
-            self.ensure_owner()?;
+            self.ensure_role(Role::Burner)?;
             // No-op if `value` is zero.
             if value == 0 {
                 return Ok(());
             }
+            self.ensure_movable(&[account])?;
             api::burn(self.id, account, value).map_err(Psp22Error::from)?;
             self.env().emit_event(Transfer {
                 from: Some(account),
@@ -299,22 +724,404 @@ mod fungibles {
     }
 
     impl Fungible {
+        /// Check if the caller holds `role`.
+        fn ensure_role(&self, role: Role) -> Result<(), Psp22Error> {
+            if !self.has_role(role, self.env().caller()) {
+                return Err(Psp22Error::Custom(String::from("Missing role")));
+            }
+            Ok(())
+        }
+
+        /// Returns the account whose tokens the caller acts on: the contract itself in custodial
+        /// mode, which requires the `Admin` role, or the caller in per-user mode.
+        fn acting_account(&self) -> Result<AccountId, Psp22Error> {
+            match self.mode {
+                Mode::Custodial => {
+                    self.ensure_role(Role::Admin)?;
+                    Ok(self.env().account_id())
+                }
+                Mode::PerUser => Ok(self.env().caller()),
+            }
+        }
+
+        /// Check that tokens can move: the contract is not paused and none of `accounts` is frozen.
+        fn ensure_movable(&self, accounts: &[AccountId]) -> Result<(), Psp22Error> {
+            if self.paused {
+                return Err(Psp22Error::Custom(String::from("Paused")));
+            }
+            if accounts.iter().any(|account| self.frozen.contains(account)) {
+                return Err(Psp22Error::Custom(String::from("Frozen")));
+            }
+            Ok(())
+        }
+
+        /// Check that a batch is not longer than `MAX_BATCH_LEN`.
+        fn ensure_batch_len(batch: &[(AccountId, Balance)]) -> Result<(), Psp22Error> {
+            if batch.len() > MAX_BATCH_LEN as usize {
+                return Err(Psp22Error::Custom(String::from("Batch too long")));
+            }
+            Ok(())
+        }
+
         /// Check if the caller is the owner of the contract.
         fn ensure_owner(&self) -> Result<(), Psp22Error> {
-            if self.owner != self.env().caller() {
+            if self.owner != Some(self.env().caller()) {
                 return Err(Psp22Error::Custom(String::from("Not the owner")));
             }
             Ok(())
         }
 
-        /// Transfer the ownership of the contract to another account.
+        /// Dispatch a `pallet-assets` call from the contract. The runtime does not report why a
+        /// call failed.
+        fn call_assets(&self, call: AssetsCall) -> Result<(), Psp22Error> {
+            self.env()
+                .call_runtime(&RuntimeCall::Assets(call))
+                .map_err(|_| Psp22Error::Custom(String::from("Runtime call failed")))
+        }
+
+        /// Revoke every role granted explicitly to `account`, emitting `RoleRevoked` for each.
+        fn revoke_all_roles(&mut self, account: AccountId) {
+            for role in [Role::Admin, Role::Minter, Role::Burner, Role::Pauser] {
+                if self.roles.contains((account, role)) {
+                    self.roles.remove((account, role));
+                    self.env().emit_event(RoleRevoked {
+                        role,
+                        account,
+                        sender: self.env().caller(),
+                    });
+                }
+            }
+        }
+
+        /// Returns whether the contract acts on its own balance or on its callers' balances.
+        #[ink(message)]
+        pub fn mode(&self) -> Mode {
+            self.mode
+        }
+
+        /// Returns whether token transfers, mints and burns are paused.
+        #[ink(message)]
+        pub fn is_paused(&self) -> bool {
+            self.paused
+        }
+
+        /// Returns whether tokens of `account` are frozen.
+        ///
+        /// # Parameters
+        /// - `account` - The account to check.
+        #[ink(message)]
+        pub fn is_frozen(&self, account: AccountId) -> bool {
+            self.frozen.contains(account)
+        }
+
+        /// Pause all token transfers, mints and burns through the contract, emitting `Paused`
+        /// unless already paused. Only callable by a `Pauser`.
+        #[ink(message)]
+        pub fn pause(&mut self) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Pauser)?;
+            if !self.paused {
+                self.paused = true;
+                self.env().emit_event(Paused { sender: self.env().caller() });
+            }
+            Ok(())
+        }
+
+        /// Resume token transfers, mints and burns through the contract, emitting `Unpaused`
+        /// unless not paused. Only callable by a `Pauser`.
+        #[ink(message)]
+        pub fn unpause(&mut self) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Pauser)?;
+            if self.paused {
+                self.paused = false;
+                self.env().emit_event(Unpaused { sender: self.env().caller() });
+            }
+            Ok(())
+        }
+
+        /// Reject token movements to or from `account` through the contract, emitting `Frozen`
+        /// unless already frozen. Only callable by a `Pauser`.
+        ///
+        /// # Parameters
+        /// - `account` - The account to freeze.
+        #[ink(message)]
+        pub fn freeze(&mut self, account: AccountId) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Pauser)?;
+            if !self.frozen.contains(account) {
+                self.frozen.insert(account, &());
+                self.env().emit_event(Frozen { account, sender: self.env().caller() });
+            }
+            Ok(())
+        }
+
+        /// Allow token movements to or from `account` again, emitting `Thawed` unless not frozen.
+        /// Only callable by a `Pauser`.
+        ///
+        /// # Parameters
+        /// - `account` - The account to thaw.
+        #[ink(message)]
+        pub fn thaw(&mut self, account: AccountId) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Pauser)?;
+            if self.frozen.contains(account) {
+                self.frozen.remove(account);
+                self.env().emit_event(Thawed { account, sender: self.env().caller() });
+            }
+            Ok(())
+        }
+
+        /// Mints tokens to each account in `mints`, emitting a `Transfer` event per non-zero
+        /// entry. The batch is all-or-nothing: if any entry fails, no tokens are minted.
+        ///
+        /// # Parameters
+        /// - `mints` - The accounts to credit and the number of tokens to mint to each.
+        #[ink(message)]
+        pub fn batch_mint(&mut self, mints: Vec<(AccountId, Balance)>) -> Result<(), Psp22Error> {
+            Self::ensure_batch_len(&mints)?;
+            for (account, value) in mints {
+                Psp22Mintable::mint(self, account, value)?;
+            }
+            Ok(())
+        }
+
+        /// Transfers tokens to each account in `transfers`, emitting a `Transfer` event per
+        /// non-zero entry. The batch is all-or-nothing: if any entry fails, no tokens are moved.
+        ///
+        /// # Parameters
+        /// - `transfers` - The recipient accounts and the number of tokens to transfer to each.
+        #[ink(message)]
+        pub fn batch_transfer(
+            &mut self,
+            transfers: Vec<(AccountId, Balance)>,
+        ) -> Result<(), Psp22Error> {
+            Self::ensure_batch_len(&transfers)?;
+            for (to, value) in transfers {
+                Psp22::transfer(self, to, value, Vec::new())?;
+            }
+            Ok(())
+        }
+
+        /// Returns the total supply that minting can never exceed, if any.
+        #[ink(message)]
+        pub fn max_supply(&self) -> Option<Balance> {
+            self.max_supply
+        }
+
+        /// Returns the mint allowance of `minter`, or `None` if its minting is only bounded by
+        /// the max supply.
         ///
         /// # Parameters
-        /// - `owner` - New owner account.
+        /// - `minter` - The account to check.
         #[ink(message)]
-        pub fn transfer_ownership(&mut self, owner: AccountId) -> Result<(), Psp22Error> {
+        pub fn mint_allowance(&self, minter: AccountId) -> Option<MintAllowance> {
+            self.mint_allowances.get(minter)
+        }
+
+        /// Limit `minter` to minting `cap` tokens every `period` blocks, starting now, or remove
+        /// the limit. Only callable by an `Admin`.
+        ///
+        /// # Parameters
+        /// - `minter` - The account to limit.
+        /// - `limit` - The `(cap, period)` pair, or `None` to remove the limit.
+        #[ink(message)]
+        pub fn set_mint_allowance(
+            &mut self,
+            minter: AccountId,
+            limit: Option<(Balance, BlockNumber)>,
+        ) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Admin)?;
+            match limit {
+                Some((cap, period)) => {
+                    let allowance = MintAllowance {
+                        cap,
+                        period,
+                        period_start: self.env().block_number(),
+                        minted: 0,
+                    };
+                    self.mint_allowances.insert(minter, &allowance);
+                }
+                None => self.mint_allowances.remove(minter),
+            }
+            Ok(())
+        }
+
+        /// Start destroying the token. Only callable by an `Admin`.
+        ///
+        /// Once started, the token is no longer live: transfers, approvals, mints and burns fail.
+        /// The token is then removed with `destroy_accounts`, `destroy_approvals` and
+        /// `finish_destroy`.
+        #[ink(message)]
+        pub fn start_destroy(&mut self) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Admin)?;
+            api::start_destroy(self.id).map_err(Psp22Error::from)?;
+            self.env().emit_event(DestroyStarted { id: self.id });
+            Ok(())
+        }
+
+        /// Remove a batch of the token's accounts once destruction has started. Callable by any
+        /// account, like the `pallet-assets` call it dispatches, and repeated until no account
+        /// is left.
+        #[ink(message)]
+        pub fn destroy_accounts(&mut self) -> Result<(), Psp22Error> {
+            self.call_assets(AssetsCall::DestroyAccounts { id: self.id })?;
+            self.env().emit_event(AccountsDestroyed { id: self.id });
+            Ok(())
+        }
+
+        /// Remove a batch of the token's approvals once destruction has started. Callable by any
+        /// account, like the `pallet-assets` call it dispatches, and repeated until no approval
+        /// is left.
+        #[ink(message)]
+        pub fn destroy_approvals(&mut self) -> Result<(), Psp22Error> {
+            self.call_assets(AssetsCall::DestroyApprovals { id: self.id })?;
+            self.env().emit_event(ApprovalsDestroyed { id: self.id });
+            Ok(())
+        }
+
+        /// Remove the token once all its accounts and approvals are destroyed. Callable by any
+        /// account, like the `pallet-assets` call it dispatches.
+        #[ink(message)]
+        pub fn finish_destroy(&mut self) -> Result<(), Psp22Error> {
+            self.call_assets(AssetsCall::FinishDestroy { id: self.id })?;
+            self.env().emit_event(Destroyed { id: self.id });
+            Ok(())
+        }
+
+        /// Set the token metadata. Only callable by an `Admin`.
+        ///
+        /// # Parameters
+        /// - `name` - The name of the token.
+        /// - `symbol` - The symbol of the token.
+        /// - `decimals` - The number of decimals of the token.
+        #[ink(message)]
+        pub fn set_metadata(
+            &mut self,
+            name: String,
+            symbol: String,
+            decimals: u8,
+        ) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Admin)?;
+            api::set_metadata(self.id, name.into(), symbol.into(), decimals).map_err(Psp22Error::from)
+        }
+
+        /// Clear the token metadata. Only callable by an `Admin`.
+        #[ink(message)]
+        pub fn clear_metadata(&mut self) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Admin)?;
+            api::clear_metadata(self.id).map_err(Psp22Error::from)
+        }
+
+        /// Returns the owner of the contract, or `None` if ownership was renounced.
+        #[ink(message)]
+        pub fn owner(&self) -> Option<AccountId> {
+            self.owner
+        }
+
+        /// Returns the account that may accept ownership of the contract, if any.
+        #[ink(message)]
+        pub fn pending_owner(&self) -> Option<AccountId> {
+            self.pending_owner
+        }
+
+        /// Propose another account as owner of the contract. Ownership only moves once the
+        /// proposed account calls `accept_ownership`. Successive calls overwrite the proposal.
+        ///
+        /// # Parameters
+        /// - `owner` - Proposed owner account.
+        #[ink(message)]
+        pub fn propose_owner(&mut self, owner: AccountId) -> Result<(), Psp22Error> {
             self.ensure_owner()?;
-            self.owner = owner;
+            self.pending_owner = Some(owner);
+            Ok(())
+        }
+
+        /// Accept ownership of the contract. Only callable by the proposed owner. Roles granted
+        /// explicitly to the previous owner are revoked.
+        #[ink(message)]
+        pub fn accept_ownership(&mut self) -> Result<(), Psp22Error> {
+            let caller = self.env().caller();
+            if self.pending_owner != Some(caller) {
+                return Err(Psp22Error::Custom(String::from("Not the pending owner")));
+            }
+            let previous_owner = self.owner;
+            if let Some(previous_owner) = previous_owner {
+                self.revoke_all_roles(previous_owner);
+            }
+            self.owner = Some(caller);
+            self.pending_owner = None;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: self.owner,
+            });
+            Ok(())
+        }
+
+        /// Give up ownership of the contract, leaving it without an owner. Roles granted
+        /// explicitly to the owner are revoked, while those of other accounts, including
+        /// `Admin`, are kept.
+        #[ink(message)]
+        pub fn renounce_ownership(&mut self) -> Result<(), Psp22Error> {
+            self.ensure_owner()?;
+            let previous_owner = self.owner;
+            self.revoke_all_roles(self.env().caller());
+            self.owner = None;
+            self.pending_owner = None;
+            self.env().emit_event(OwnershipTransferred {
+                previous_owner,
+                new_owner: None,
+            });
+            Ok(())
+        }
+
+        /// Returns whether `account` holds `role`. The owner always holds the `Admin` role.
+        ///
+        /// # Parameters
+        /// - `role` - The role to check.
+        /// - `account` - The account to check.
+        #[ink(message)]
+        pub fn has_role(&self, role: Role, account: AccountId) -> bool {
+            (role == Role::Admin && Some(account) == self.owner)
+                || self.roles.contains((account, role))
+        }
+
+        /// Grants `role` to `account`. Only callable by an `Admin`.
+        ///
+        /// # Parameters
+        /// - `role` - The role to grant.
+        /// - `account` - The account receiving the role.
+        #[ink(message)]
+        pub fn grant_role(&mut self, role: Role, account: AccountId) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Admin)?;
+            // No-op if `account` already holds the role.
+            if self.has_role(role, account) {
+                return Ok(());
+            }
+            self.roles.insert((account, role), &());
+            self.env().emit_event(RoleGranted {
+                role,
+                account,
+                sender: self.env().caller(),
+            });
+            Ok(())
+        }
+
+        /// Revokes `role` from `account`. Only callable by an `Admin`. The owner's implicit
+        /// `Admin` role cannot be revoked.
+        ///
+        /// # Parameters
+        /// - `role` - The role to revoke.
+        /// - `account` - The account losing the role.
+        #[ink(message)]
+        pub fn revoke_role(&mut self, role: Role, account: AccountId) -> Result<(), Psp22Error> {
+            self.ensure_role(Role::Admin)?;
+            // No-op if `account` was not granted the role.
+            if !self.roles.contains((account, role)) {
+                return Ok(());
+            }
+            self.roles.remove((account, role));
+            self.env().emit_event(RoleRevoked {
+                role,
+                account,
+                sender: self.env().caller(),
+            });
             Ok(())
         }
     }
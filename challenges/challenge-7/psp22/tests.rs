@@ -79,6 +79,77 @@ fn new_constructor_works(mut session: Session) {
 	);
 }
 
+fn deploy_from_existing(
+	session: &mut Session<Pop>,
+	mode: Mode,
+	max_supply: Option<Balance>,
+) -> Result<AccountId, Psp22Error> {
+	deploy(session, "from_existing", from_existing_args(mode, max_supply))
+}
+
+#[drink::test(sandbox = Pop)]
+fn from_existing_constructor_fails_with_unknown_token(mut session: Session) {
+	let _ = env_logger::try_init();
+	assert_eq!(
+		deploy_from_existing(&mut session, Mode::Custodial, None),
+		Err(Psp22Error::Custom(String::from("Token does not exist")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn from_existing_constructor_fails_when_not_token_owner(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Token owned by `ALICE` rather than the contract.
+	assert_ok!(session.sandbox().create(&TOKEN, &ALICE, MIN_BALANCE));
+	assert_eq!(
+		deploy_from_existing(&mut session, Mode::Custodial, None),
+		Err(Psp22Error::Custom(String::from("Not the token owner")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn from_existing_constructor_fails_above_max_supply(mut session: Session) {
+	let _ = env_logger::try_init();
+	assert_ok!(session.sandbox().create(&TOKEN, &ALICE, MIN_BALANCE));
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &BOB, AMOUNT));
+	assert_eq!(
+		deploy_from_existing(&mut session, Mode::Custodial, Some(AMOUNT - 1)),
+		Err(Psp22Error::Custom(String::from("Max supply exceeded")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn from_existing_constructor_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	let cap = AMOUNT * 2;
+	// Token owned by the address the contract is deployed to, with some tokens in circulation.
+	let contract = contract_address("from_existing", (TOKEN, Mode::PerUser, Some(cap)).encode());
+	assert_ok!(session.sandbox().create(&TOKEN, &contract, MIN_BALANCE));
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &BOB, AMOUNT));
+	// Deploy a new contract for the existing token.
+	assert_eq!(deploy_from_existing(&mut session, Mode::PerUser, Some(cap)), Ok(contract.clone()));
+	assert_eq!(mode(&mut session), Mode::PerUser);
+	assert_eq!(max_supply(&mut session), Some(cap));
+	assert_eq!(total_supply(&mut session), AMOUNT);
+	// Successfully mint up to the max supply.
+	session.set_actor(ALICE);
+	assert_ok!(mint(&mut session, ALICE, AMOUNT));
+	assert_eq!(
+		mint(&mut session, ALICE, 1),
+		Err(Psp22Error::Custom(String::from("Max supply exceeded")))
+	);
+	// Successfully transfer tokens minted before the contract was deployed.
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &contract, AMOUNT));
+	session.set_actor(BOB);
+	assert_ok!(transfer(&mut session, CHARLIE, AMOUNT / 2));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT / 2);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT / 2);
+	// Successfully burn, as the contract is the token's admin.
+	session.set_actor(ALICE);
+	assert_ok!(burn(&mut session, CHARLIE, AMOUNT / 2));
+	assert_eq!(total_supply(&mut session), AMOUNT + AMOUNT / 2);
+}
+
 // PSP-22 tests.
 
 #[drink::test(sandbox = Pop)]
@@ -281,14 +352,24 @@ fn transfer_from_works(mut session: Session) {
 	assert_eq!(session.sandbox().allowance(&TOKEN, &ALICE, &contract.clone()), value);
 	assert_eq!(session.sandbox().balance_of(&TOKEN, &ALICE), value);
 	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), value);
-	// Successfully emit event.
-	assert_last_contract_event!(
-		&session,
-		Approval {
-			owner: account_id_from_slice(&ALICE),
-			spender: account_id_from_slice(&contract),
-			value,
-		}
+	// Successfully emit events: tokens move from `from`, and its allowance to the contract is
+	// reduced.
+	assert_eq!(
+		contract_events(&session),
+		vec![
+			Transfer {
+				from: Some(account_id_from_slice(&ALICE)),
+				to: Some(account_id_from_slice(&BOB)),
+				value,
+			}
+			.encode(),
+			Approval {
+				owner: account_id_from_slice(&ALICE),
+				spender: account_id_from_slice(&contract),
+				value,
+			}
+			.encode(),
+		]
 	);
 }
 
@@ -525,6 +606,65 @@ fn token_metadata(mut session: Session) {
 	assert_eq!(token_decimals(&mut session), decimals);
 }
 
+#[drink::test(sandbox = Pop)]
+fn with_metadata_constructor_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	assert_ok!(deploy(
+		&mut session,
+		"with_metadata",
+		vec![
+			TOKEN.to_string(),
+			MIN_BALANCE.to_string(),
+			"Custodial".to_string(),
+			"None".to_string(),
+			"\"Paseo Token\"".to_string(),
+			"\"PAS\"".to_string(),
+			"10".to_string(),
+		],
+	));
+	assert_eq!(token_name(&mut session), Some(String::from("Paseo Token")));
+	assert_eq!(token_symbol(&mut session), Some(String::from("PAS")));
+	assert_eq!(token_decimals(&mut session), 10);
+}
+
+#[drink::test(sandbox = Pop)]
+fn set_metadata_fails_without_admin_role(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(BOB);
+	assert_eq!(
+		set_metadata(&mut session, String::from("Paseo Token"), String::from("PAS"), 10),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+	assert_eq!(
+		clear_metadata(&mut session),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn set_and_clear_metadata_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	let name: String = String::from("Paseo Token");
+	let symbol: String = String::from("PAS");
+	let decimals: u8 = 10;
+	// Successfully set metadata.
+	assert_ok!(set_metadata(&mut session, name.clone(), symbol.clone(), decimals));
+	assert_eq!(token_name(&mut session), Some(name));
+	assert_eq!(token_symbol(&mut session), Some(symbol));
+	assert_eq!(token_decimals(&mut session), decimals);
+	// Successfully clear metadata.
+	assert_ok!(clear_metadata(&mut session));
+	assert_eq!(token_name(&mut session), None);
+	assert_eq!(token_symbol(&mut session), None);
+	assert_eq!(token_decimals(&mut session), 0);
+}
+
 // PSP-22 Mintable & Burnable tests.
 
 #[drink::test(sandbox = Pop)]
@@ -633,6 +773,644 @@ fn burn_works(mut session: Session) {
 	);
 }
 
+// Supply cap & mint allowance tests.
+
+#[drink::test(sandbox = Pop)]
+fn mint_fails_above_max_supply(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract with a max supply.
+	assert_ok!(deploy(
+		&mut session,
+		"with_mode",
+		vec![
+			TOKEN.to_string(),
+			MIN_BALANCE.to_string(),
+			"Custodial".to_string(),
+			format!("Some({})", AMOUNT),
+		],
+	));
+	session.set_actor(ALICE);
+	assert_eq!(max_supply(&mut session), Some(AMOUNT));
+	// Minting up to the max supply succeeds.
+	assert_ok!(mint(&mut session, ALICE, AMOUNT - 1));
+	assert_ok!(mint(&mut session, BOB, 1));
+	assert_eq!(session.sandbox().total_supply(&TOKEN), AMOUNT);
+	// Minting above the max supply fails.
+	assert_eq!(
+		mint(&mut session, BOB, 1),
+		Err(Psp22Error::Custom(String::from("Max supply exceeded")))
+	);
+	// Burning frees up room below the max supply.
+	assert_ok!(burn(&mut session, ALICE, 1));
+	assert_ok!(mint(&mut session, BOB, 1));
+}
+
+#[drink::test(sandbox = Pop)]
+fn mint_fails_above_mint_allowance(mut session: Session) {
+	let _ = env_logger::try_init();
+	let period = 10;
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(grant_role(&mut session, Role::Minter, BOB));
+	assert_ok!(set_mint_allowance(&mut session, BOB, Some((AMOUNT, period))));
+	// Minting up to the allowance succeeds.
+	session.set_actor(BOB);
+	assert_ok!(mint(&mut session, BOB, AMOUNT - 1));
+	assert_ok!(mint(&mut session, BOB, 1));
+	// Minting above the allowance fails until the next period.
+	assert_eq!(
+		mint(&mut session, BOB, 1),
+		Err(Psp22Error::Custom(String::from("Mint allowance exceeded")))
+	);
+	session.sandbox().build_blocks(period);
+	assert_ok!(mint(&mut session, BOB, AMOUNT));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT * 2);
+	// Minters without an allowance are not limited.
+	session.set_actor(ALICE);
+	assert_ok!(mint(&mut session, ALICE, AMOUNT * 2));
+	// Only an `Admin` can set allowances.
+	session.set_actor(BOB);
+	assert_eq!(
+		set_mint_allowance(&mut session, BOB, None),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+	// Removing the allowance lifts the limit.
+	session.set_actor(ALICE);
+	assert_ok!(set_mint_allowance(&mut session, BOB, None));
+	assert_eq!(mint_allowance(&mut session, BOB), None);
+	session.set_actor(BOB);
+	assert_ok!(mint(&mut session, BOB, AMOUNT * 2));
+}
+
+// Batch tests.
+
+#[drink::test(sandbox = Pop)]
+fn batch_mint_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	// Successfully mint to each account.
+	assert_ok!(batch_mint(&mut session, vec![(ALICE, AMOUNT), (BOB, AMOUNT * 2), (CHARLIE, 0)]));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &ALICE), AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT * 2);
+	assert_eq!(session.sandbox().total_supply(&TOKEN), AMOUNT * 3);
+	// Successfully emit one event per non-zero entry.
+	assert_eq!(
+		contract_events(&session),
+		vec![
+			Transfer { from: None, to: Some(account_id_from_slice(&ALICE)), value: AMOUNT }
+				.encode(),
+			Transfer { from: None, to: Some(account_id_from_slice(&BOB)), value: AMOUNT * 2 }
+				.encode(),
+		]
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn batch_mint_is_all_or_nothing(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(freeze(&mut session, CHARLIE));
+	// Failing entry reverts the whole batch.
+	assert_eq!(
+		batch_mint(&mut session, vec![(ALICE, AMOUNT), (CHARLIE, AMOUNT)]),
+		Err(Psp22Error::Custom(String::from("Frozen")))
+	);
+	assert_eq!(session.sandbox().total_supply(&TOKEN), 0);
+}
+
+#[drink::test(sandbox = Pop)]
+fn batch_fails_when_too_long(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	let batch = vec![(BOB, 1); MAX_BATCH_LEN as usize + 1];
+	assert_eq!(
+		batch_mint(&mut session, batch.clone()),
+		Err(Psp22Error::Custom(String::from("Batch too long")))
+	);
+	assert_eq!(
+		batch_transfer(&mut session, batch),
+		Err(Psp22Error::Custom(String::from("Batch too long")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn batch_transfer_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	let contract = deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &contract, AMOUNT * 2));
+	// Failed with `InsufficientBalance`, nothing is transferred.
+	assert_eq!(
+		batch_transfer(&mut session, vec![(BOB, AMOUNT), (CHARLIE, AMOUNT * 2)]),
+		Err(Psp22Error::InsufficientBalance)
+	);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), 0);
+	// Successfully transfer to each account.
+	assert_ok!(batch_transfer(&mut session, vec![(BOB, AMOUNT), (CHARLIE, AMOUNT / 2)]));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &contract), AMOUNT / 2);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT / 2);
+	// Successfully emit one event per entry.
+	assert_eq!(
+		contract_events(&session),
+		vec![
+			Transfer {
+				from: Some(account_id_from_slice(&contract)),
+				to: Some(account_id_from_slice(&BOB)),
+				value: AMOUNT,
+			}
+			.encode(),
+			Transfer {
+				from: Some(account_id_from_slice(&contract)),
+				to: Some(account_id_from_slice(&CHARLIE)),
+				value: AMOUNT / 2,
+			}
+			.encode(),
+		]
+	);
+}
+
+// Token destruction tests.
+
+#[drink::test(sandbox = Pop)]
+fn start_destroy_fails_without_admin_role(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(BOB);
+	assert_eq!(start_destroy(&mut session), Err(Psp22Error::Custom(String::from("Missing role"))));
+}
+
+#[drink::test(sandbox = Pop)]
+fn start_destroy_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(mint(&mut session, ALICE, AMOUNT));
+	// Successfully start destroying the token.
+	assert_ok!(start_destroy(&mut session));
+	// Successfully emit event.
+	assert_last_contract_event!(&session, DestroyStarted { id: TOKEN });
+	// Token is not live anymore.
+	assert_err!(mint(&mut session, ALICE, AMOUNT), Error::Module(Assets(AssetNotLive)));
+	// `pallet-assets` returns `IncorrectStatus` error when destruction already started.
+	assert_err!(start_destroy(&mut session), Error::Module(Assets(IncorrectStatus)));
+}
+
+#[drink::test(sandbox = Pop)]
+fn destroy_fails_before_start_destroy(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(mint(&mut session, ALICE, AMOUNT));
+	// `pallet-assets` rejects the calls while the token is live.
+	let failed = Err(Psp22Error::Custom(String::from("Runtime call failed")));
+	assert_eq!(destroy_accounts(&mut session), failed);
+	assert_eq!(destroy_approvals(&mut session), failed);
+	assert_eq!(finish_destroy(&mut session), failed);
+	assert!(session.sandbox().asset_exists(&TOKEN));
+}
+
+#[drink::test(sandbox = Pop)]
+fn destroy_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(mint(&mut session, ALICE, AMOUNT));
+	assert_ok!(mint(&mut session, BOB, AMOUNT));
+	assert_ok!(session.sandbox().approve(&TOKEN, &ALICE, &BOB, AMOUNT));
+	assert_ok!(start_destroy(&mut session));
+	// Any account can finish destroying the token.
+	session.set_actor(CHARLIE);
+	// The token cannot be removed while it has accounts or approvals.
+	assert_eq!(
+		finish_destroy(&mut session),
+		Err(Psp22Error::Custom(String::from("Runtime call failed")))
+	);
+	// Successfully destroy accounts.
+	assert_ok!(destroy_accounts(&mut session));
+	assert_last_contract_event!(&session, AccountsDestroyed { id: TOKEN });
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &ALICE), 0);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), 0);
+	// Successfully destroy approvals.
+	assert_ok!(destroy_approvals(&mut session));
+	assert_last_contract_event!(&session, ApprovalsDestroyed { id: TOKEN });
+	assert_eq!(session.sandbox().allowance(&TOKEN, &ALICE, &BOB), 0);
+	// Successfully remove the token.
+	assert_ok!(finish_destroy(&mut session));
+	assert_last_contract_event!(&session, Destroyed { id: TOKEN });
+	assert!(!session.sandbox().asset_exists(&TOKEN));
+}
+
+// Role-based access control tests.
+
+#[drink::test(sandbox = Pop)]
+fn deployer_holds_all_roles(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	for role in [Role::Admin, Role::Minter, Role::Burner, Role::Pauser] {
+		assert!(has_role(&mut session, role, ALICE));
+		assert!(!has_role(&mut session, role, BOB));
+	}
+}
+
+#[drink::test(sandbox = Pop)]
+fn grant_role_fails_without_admin_role(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(BOB);
+	assert_eq!(
+		grant_role(&mut session, Role::Minter, BOB),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+	assert_eq!(
+		revoke_role(&mut session, Role::Minter, ALICE),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn grant_role_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	let contract = deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	// Successfully grant role.
+	assert_ok!(grant_role(&mut session, Role::Minter, BOB));
+	assert!(has_role(&mut session, Role::Minter, BOB));
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		RoleGranted {
+			role: Role::Minter,
+			account: account_id_from_slice(&BOB),
+			sender: account_id_from_slice(&ALICE),
+		}
+	);
+	// No-op if the account already holds the role, no events are emitted.
+	assert_ok!(grant_role(&mut session, Role::Admin, ALICE));
+	assert_last_contract_event!(
+		&session,
+		RoleGranted {
+			role: Role::Minter,
+			account: account_id_from_slice(&BOB),
+			sender: account_id_from_slice(&ALICE),
+		}
+	);
+	// Minter can mint but cannot move the contract's own balance.
+	session.set_actor(BOB);
+	assert_ok!(mint(&mut session, BOB, AMOUNT));
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &contract, AMOUNT));
+	assert_eq!(
+		transfer(&mut session, BOB, AMOUNT),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+	assert_eq!(
+		burn(&mut session, BOB, AMOUNT),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn revoke_role_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(grant_role(&mut session, Role::Minter, BOB));
+	// Successfully revoke role.
+	assert_ok!(revoke_role(&mut session, Role::Minter, BOB));
+	assert!(!has_role(&mut session, Role::Minter, BOB));
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		RoleRevoked {
+			role: Role::Minter,
+			account: account_id_from_slice(&BOB),
+			sender: account_id_from_slice(&ALICE),
+		}
+	);
+	session.set_actor(BOB);
+	assert_eq!(
+		mint(&mut session, BOB, AMOUNT),
+		Err(Psp22Error::Custom(String::from("Missing role")))
+	);
+	// The owner's implicit `Admin` role cannot be revoked.
+	session.set_actor(ALICE);
+	assert_ok!(revoke_role(&mut session, Role::Admin, ALICE));
+	assert!(has_role(&mut session, Role::Admin, ALICE));
+}
+
+// Per-user mode tests.
+
+fn deploy_per_user(session: &mut Session<Pop>) -> Result<AccountId, Psp22Error> {
+	deploy(
+		session,
+		"with_mode",
+		vec![
+			TOKEN.to_string(),
+			MIN_BALANCE.to_string(),
+			"PerUser".to_string(),
+			"None".to_string(),
+		],
+	)
+}
+
+#[drink::test(sandbox = Pop)]
+fn with_mode_constructor_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_per_user(&mut session).unwrap();
+	assert!(session.sandbox().asset_exists(&TOKEN));
+	assert_eq!(mode(&mut session), Mode::PerUser);
+}
+
+#[drink::test(sandbox = Pop)]
+fn per_user_transfer_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	let contract = deploy_per_user(&mut session).unwrap();
+	let value = AMOUNT / 4;
+	// Mint tokens and approve the contract to move them.
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &BOB, AMOUNT));
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &contract, AMOUNT));
+	// Any holder can transfer their own tokens.
+	session.set_actor(BOB);
+	assert_ok!(transfer(&mut session, CHARLIE, value));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT - value);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), value);
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		Transfer {
+			from: Some(account_id_from_slice(&BOB)),
+			to: Some(account_id_from_slice(&CHARLIE)),
+			value,
+		}
+	);
+	// Failed with `InsufficientBalance`.
+	assert_eq!(transfer(&mut session, CHARLIE, AMOUNT), Err(Psp22Error::InsufficientBalance));
+}
+
+#[drink::test(sandbox = Pop)]
+fn per_user_allowances_work(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	let contract = deploy_per_user(&mut session).unwrap();
+	let value = AMOUNT / 2;
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &BOB, AMOUNT));
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &contract, AMOUNT));
+	// Allowances are recorded per caller.
+	session.set_actor(BOB);
+	assert_ok!(approve(&mut session, CHARLIE, value));
+	assert_eq!(allowance(&mut session, BOB, CHARLIE), value);
+	assert_last_contract_event!(
+		&session,
+		Approval {
+			owner: account_id_from_slice(&BOB),
+			spender: account_id_from_slice(&CHARLIE),
+			value,
+		}
+	);
+	assert_ok!(increase_allowance(&mut session, CHARLIE, 2));
+	assert_ok!(decrease_allowance(&mut session, CHARLIE, 2));
+	assert_eq!(allowance(&mut session, BOB, CHARLIE), value);
+	assert_eq!(
+		decrease_allowance(&mut session, CHARLIE, value + 1),
+		Err(Psp22Error::InsufficientAllowance)
+	);
+	// The spender is limited by the recorded allowance.
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		transfer_from(&mut session, BOB, ALICE, value + 1),
+		Err(Psp22Error::InsufficientAllowance)
+	);
+	assert_ok!(transfer_from(&mut session, BOB, ALICE, value));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &ALICE), value);
+	assert_eq!(allowance(&mut session, BOB, CHARLIE), 0);
+	assert_eq!(
+		contract_events(&session),
+		vec![
+			Transfer {
+				from: Some(account_id_from_slice(&BOB)),
+				to: Some(account_id_from_slice(&ALICE)),
+				value,
+			}
+			.encode(),
+			Approval {
+				owner: account_id_from_slice(&BOB),
+				spender: account_id_from_slice(&CHARLIE),
+				value: 0,
+			}
+			.encode(),
+		]
+	);
+}
+
+// Pause & freeze tests.
+
+#[drink::test(sandbox = Pop)]
+fn pause_fails_without_pauser_role(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(BOB);
+	assert_eq!(pause(&mut session), Err(Psp22Error::Custom(String::from("Missing role"))));
+	assert_eq!(freeze(&mut session, ALICE), Err(Psp22Error::Custom(String::from("Missing role"))));
+}
+
+#[drink::test(sandbox = Pop)]
+fn pause_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	let contract = deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &contract, AMOUNT));
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &ALICE, AMOUNT));
+	assert_ok!(session.sandbox().approve(&TOKEN, &ALICE, &contract, AMOUNT));
+	// Successfully pause.
+	assert_ok!(pause(&mut session));
+	assert!(is_paused(&mut session));
+	// Successfully emit event.
+	assert_last_contract_event!(&session, Paused { sender: account_id_from_slice(&ALICE) });
+	let paused = Err(Psp22Error::Custom(String::from("Paused")));
+	assert_eq!(transfer(&mut session, BOB, AMOUNT), paused);
+	assert_eq!(transfer_from(&mut session, ALICE, BOB, AMOUNT), paused);
+	assert_eq!(mint(&mut session, BOB, AMOUNT), paused);
+	assert_eq!(burn(&mut session, ALICE, AMOUNT), paused);
+	// Approvals are not affected.
+	assert_ok!(approve(&mut session, BOB, AMOUNT));
+	// Successfully unpause.
+	assert_ok!(unpause(&mut session));
+	assert!(!is_paused(&mut session));
+	// Successfully emit event.
+	assert_last_contract_event!(&session, Unpaused { sender: account_id_from_slice(&ALICE) });
+	// No-op if the contract is not paused, no events are emitted.
+	assert_ok!(freeze(&mut session, CHARLIE));
+	assert_ok!(unpause(&mut session));
+	assert_last_contract_event!(
+		&session,
+		Frozen { account: account_id_from_slice(&CHARLIE), sender: account_id_from_slice(&ALICE) }
+	);
+	assert_ok!(transfer(&mut session, BOB, AMOUNT));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT);
+}
+
+#[drink::test(sandbox = Pop)]
+fn freeze_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	let contract = deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &contract, AMOUNT));
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &BOB, AMOUNT));
+	// Successfully freeze.
+	assert_ok!(freeze(&mut session, BOB));
+	assert!(is_frozen(&mut session, BOB));
+	assert!(!is_frozen(&mut session, CHARLIE));
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		Frozen { account: account_id_from_slice(&BOB), sender: account_id_from_slice(&ALICE) }
+	);
+	let frozen = Err(Psp22Error::Custom(String::from("Frozen")));
+	assert_eq!(transfer(&mut session, BOB, AMOUNT), frozen);
+	assert_eq!(mint(&mut session, BOB, AMOUNT), frozen);
+	assert_eq!(burn(&mut session, BOB, AMOUNT), frozen);
+	// Other accounts are not affected.
+	assert_ok!(transfer(&mut session, CHARLIE, AMOUNT / 2));
+	// Successfully thaw.
+	assert_ok!(thaw(&mut session, BOB));
+	assert!(!is_frozen(&mut session, BOB));
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		Thawed { account: account_id_from_slice(&BOB), sender: account_id_from_slice(&ALICE) }
+	);
+	assert_ok!(transfer(&mut session, BOB, AMOUNT / 2));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT + AMOUNT / 2);
+}
+
+// Ownership tests.
+
+#[drink::test(sandbox = Pop)]
+fn owner_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	assert_eq!(owner(&mut session), Some(ALICE));
+	assert_eq!(pending_owner(&mut session), None);
+}
+
+#[drink::test(sandbox = Pop)]
+fn propose_owner_fails_when_not_owner(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(BOB);
+	assert_eq!(
+		propose_owner(&mut session, BOB),
+		Err(Psp22Error::Custom(String::from("Not the owner")))
+	);
+	assert_eq!(
+		renounce_ownership(&mut session),
+		Err(Psp22Error::Custom(String::from("Not the owner")))
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn accept_ownership_fails_when_not_pending_owner(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	// Nothing proposed yet.
+	session.set_actor(BOB);
+	assert_eq!(
+		accept_ownership(&mut session),
+		Err(Psp22Error::Custom(String::from("Not the pending owner")))
+	);
+	// Only the proposed account can accept.
+	session.set_actor(ALICE);
+	assert_ok!(propose_owner(&mut session, BOB));
+	session.set_actor(CHARLIE);
+	assert_eq!(
+		accept_ownership(&mut session),
+		Err(Psp22Error::Custom(String::from("Not the pending owner")))
+	);
+	assert_eq!(owner(&mut session), Some(ALICE));
+}
+
+#[drink::test(sandbox = Pop)]
+fn two_step_ownership_transfer_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	// Proposing does not move ownership.
+	assert_ok!(propose_owner(&mut session, CHARLIE));
+	assert_ok!(propose_owner(&mut session, BOB));
+	assert_eq!(owner(&mut session), Some(ALICE));
+	assert_eq!(pending_owner(&mut session), Some(BOB));
+	// Successfully accept ownership.
+	session.set_actor(BOB);
+	assert_ok!(accept_ownership(&mut session));
+	assert_eq!(owner(&mut session), Some(BOB));
+	assert_eq!(pending_owner(&mut session), None);
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		OwnershipTransferred {
+			previous_owner: Some(account_id_from_slice(&ALICE)),
+			new_owner: Some(account_id_from_slice(&BOB)),
+		}
+	);
+	// The `Admin` role follows ownership and the previous owner's other roles are revoked.
+	assert!(has_role(&mut session, Role::Admin, BOB));
+	for role in [Role::Admin, Role::Minter, Role::Burner, Role::Pauser] {
+		assert!(!has_role(&mut session, role, ALICE));
+	}
+}
+
+#[drink::test(sandbox = Pop)]
+fn renounce_ownership_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	// Deploy a new contract.
+	deploy_with_default(&mut session).unwrap();
+	session.set_actor(ALICE);
+	assert_ok!(propose_owner(&mut session, BOB));
+	// Successfully renounce ownership.
+	assert_ok!(renounce_ownership(&mut session));
+	assert_eq!(owner(&mut session), None);
+	assert_eq!(pending_owner(&mut session), None);
+	// Successfully emit event.
+	assert_last_contract_event!(
+		&session,
+		OwnershipTransferred { previous_owner: Some(account_id_from_slice(&ALICE)), new_owner: None }
+	);
+	// The pending proposal is dropped.
+	session.set_actor(BOB);
+	assert_eq!(
+		accept_ownership(&mut session),
+		Err(Psp22Error::Custom(String::from("Not the pending owner")))
+	);
+	for role in [Role::Admin, Role::Minter, Role::Burner, Role::Pauser] {
+		assert!(!has_role(&mut session, role, ALICE));
+	}
+}
+
 // Deploy the contract with `NO_SALT and `INIT_VALUE`.
 fn deploy(
 	session: &mut Session<Pop>,
@@ -650,6 +1428,35 @@ fn deploy(
 	)
 }
 
+// Formats the arguments of the `from_existing` constructor.
+fn from_existing_args(mode: Mode, max_supply: Option<Balance>) -> Vec<String> {
+	let max_supply = match max_supply {
+		Some(max_supply) => format!("Some({})", max_supply),
+		None => "None".to_string(),
+	};
+	vec![TOKEN.to_string(), format!("{:?}", mode), max_supply]
+}
+
+// Returns the address `ALICE` deploys the contract to with the `constructor` and its encoded
+// `args`, following the default address generator of `pallet-contracts`.
+fn contract_address(constructor: &str, args: Vec<u8>) -> AccountId {
+	let code_hash = blake2_256(&BundleProvider::local().unwrap().wasm);
+	let selector = blake2_256(constructor.as_bytes());
+	let input = [&selector[..4], &args[..]].concat();
+	AccountId::new(blake2_256(&(b"contract_addr_v1", ALICE, code_hash, input, NO_SALT).encode()))
+}
+
+fn blake2_256(input: &[u8]) -> [u8; 32] {
+	let mut output = [0u8; 32];
+	ink::env::hash_bytes::<ink::env::hash::Blake2x256>(input, &mut output);
+	output
+}
+
+// Returns the encoded events emitted by the contract during the last call.
+fn contract_events(session: &Session<Pop>) -> Vec<Vec<u8>> {
+	session.record().last_event_batch().contract_events()
+}
+
 // A set of helper methods to test the contract calls.
 
 fn total_supply(session: &mut Session<Pop>) -> Balance {
@@ -769,3 +1576,165 @@ fn burn(session: &mut Session<Pop>, account: AccountId, amount: Balance) -> Resu
 		None,
 	)
 }
+
+fn has_role(session: &mut Session<Pop>, role: Role, account: AccountId) -> bool {
+	call::<Pop, bool, Psp22Error>(
+		session,
+		"has_role",
+		vec![format!("{:?}", role), account.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn grant_role(session: &mut Session<Pop>, role: Role, account: AccountId) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(
+		session,
+		"grant_role",
+		vec![format!("{:?}", role), account.to_string()],
+		None,
+	)
+}
+
+fn revoke_role(session: &mut Session<Pop>, role: Role, account: AccountId) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(
+		session,
+		"revoke_role",
+		vec![format!("{:?}", role), account.to_string()],
+		None,
+	)
+}
+
+fn owner(session: &mut Session<Pop>) -> Option<AccountId> {
+	call::<Pop, Option<AccountId>, Psp22Error>(session, "owner", vec![], None).unwrap()
+}
+
+fn pending_owner(session: &mut Session<Pop>) -> Option<AccountId> {
+	call::<Pop, Option<AccountId>, Psp22Error>(session, "pending_owner", vec![], None).unwrap()
+}
+
+fn propose_owner(session: &mut Session<Pop>, owner: AccountId) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "propose_owner", vec![owner.to_string()], None)
+}
+
+fn accept_ownership(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "accept_ownership", vec![], None)
+}
+
+fn renounce_ownership(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "renounce_ownership", vec![], None)
+}
+
+fn mode(session: &mut Session<Pop>) -> Mode {
+	call::<Pop, Mode, Psp22Error>(session, "mode", vec![], None).unwrap()
+}
+
+fn set_metadata(
+	session: &mut Session<Pop>,
+	name: String,
+	symbol: String,
+	decimals: u8,
+) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(
+		session,
+		"set_metadata",
+		vec![format!("{:?}", name), format!("{:?}", symbol), decimals.to_string()],
+		None,
+	)
+}
+
+fn clear_metadata(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "clear_metadata", vec![], None)
+}
+
+fn is_paused(session: &mut Session<Pop>) -> bool {
+	call::<Pop, bool, Psp22Error>(session, "is_paused", vec![], None).unwrap()
+}
+
+fn is_frozen(session: &mut Session<Pop>, account: AccountId) -> bool {
+	call::<Pop, bool, Psp22Error>(session, "is_frozen", vec![account.to_string()], None).unwrap()
+}
+
+fn pause(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "pause", vec![], None)
+}
+
+fn unpause(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "unpause", vec![], None)
+}
+
+fn freeze(session: &mut Session<Pop>, account: AccountId) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "freeze", vec![account.to_string()], None)
+}
+
+fn thaw(session: &mut Session<Pop>, account: AccountId) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "thaw", vec![account.to_string()], None)
+}
+
+fn max_supply(session: &mut Session<Pop>) -> Option<Balance> {
+	call::<Pop, Option<Balance>, Psp22Error>(session, "max_supply", vec![], None).unwrap()
+}
+
+fn mint_allowance(session: &mut Session<Pop>, minter: AccountId) -> Option<MintAllowance> {
+	call::<Pop, Option<MintAllowance>, Psp22Error>(
+		session,
+		"mint_allowance",
+		vec![minter.to_string()],
+		None,
+	)
+	.unwrap()
+}
+
+fn set_mint_allowance(
+	session: &mut Session<Pop>,
+	minter: AccountId,
+	limit: Option<(Balance, u32)>,
+) -> Result<(), Psp22Error> {
+	let limit = match limit {
+		Some((cap, period)) => format!("Some(({}, {}))", cap, period),
+		None => "None".to_string(),
+	};
+	call::<Pop, (), Psp22Error>(
+		session,
+		"set_mint_allowance",
+		vec![minter.to_string(), limit],
+		None,
+	)
+}
+
+fn batch_mint(
+	session: &mut Session<Pop>,
+	mints: Vec<(AccountId, Balance)>,
+) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "batch_mint", vec![batch_arg(mints)], None)
+}
+
+fn batch_transfer(
+	session: &mut Session<Pop>,
+	transfers: Vec<(AccountId, Balance)>,
+) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "batch_transfer", vec![batch_arg(transfers)], None)
+}
+
+// Formats a batch as a contract call argument, e.g. `[(5Grw..., 100), (5FHn..., 200)]`.
+fn batch_arg(batch: Vec<(AccountId, Balance)>) -> String {
+	let entries: Vec<String> =
+		batch.iter().map(|(account, value)| format!("({}, {})", account, value)).collect();
+	format!("[{}]", entries.join(", "))
+}
+
+fn start_destroy(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "start_destroy", vec![], None)
+}
+
+fn destroy_accounts(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "destroy_accounts", vec![], None)
+}
+
+fn destroy_approvals(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "destroy_approvals", vec![], None)
+}
+
+fn finish_destroy(session: &mut Session<Pop>) -> Result<(), Psp22Error> {
+	call::<Pop, (), Psp22Error>(session, "finish_destroy", vec![], None)
+}
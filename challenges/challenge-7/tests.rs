@@ -0,0 +1,481 @@
+use drink::{
+	assert_ok, call,
+	devnet::{AccountId, Balance, Runtime},
+	session::Session,
+	AssetsAPI, TestExternalities, NO_SALT,
+};
+use minidao_common::{DaoError, VestingSchedule};
+use pop_api::primitives::TokenId;
+
+use super::dao::*;
+
+const UNIT: Balance = 10_000_000_000;
+const INIT_AMOUNT: Balance = 100_000_000 * UNIT;
+const ALICE: AccountId = AccountId::new([1u8; 32]);
+const BOB: AccountId = AccountId::new([2_u8; 32]);
+const CHARLIE: AccountId = AccountId::new([3_u8; 32]);
+const SUPERDAO: AccountId = AccountId::new([4_u8; 32]);
+const AMOUNT: Balance = MIN_BALANCE * 4;
+const MIN_BALANCE: Balance = 10_000;
+const TOKEN: TokenId = 1;
+// Number of blocks a treasury proposal stays open for prevotes, as set by the Dao.
+const PREVOTE_PERIOD: u32 = 100;
+
+// The contract bundle provider.
+//
+// See https://github.com/r0gue-io/pop-drink/blob/main/crates/drink/drink/test-macro/src/lib.rs for more information.
+#[drink::contract_bundle_provider]
+enum BundleProvider {}
+
+/// Sandbox environment for Pop Devnet Runtime.
+pub struct Pop {
+	ext: TestExternalities,
+}
+
+impl Default for Pop {
+	fn default() -> Self {
+		// Initialising genesis state, providing accounts with an initial balance.
+		let balances: Vec<(AccountId, u128)> =
+			vec![(ALICE, INIT_AMOUNT), (BOB, INIT_AMOUNT), (CHARLIE, INIT_AMOUNT)];
+		let ext = BlockBuilder::<Runtime>::new_ext(balances);
+		Self { ext }
+	}
+}
+
+// Implement core functionalities for the `Pop` sandbox.
+drink::impl_sandbox!(Pop, Runtime, ALICE);
+
+// Deploy the Dao with the governance token `TOKEN`, and give `BOB` and `CHARLIE` each `AMOUNT`
+// tokens as registered voters.
+fn deploy_with_voters(session: &mut Session<Pop>) -> AccountId {
+	assert_ok!(session.sandbox().create(&TOKEN, &ALICE, MIN_BALANCE));
+	let dao = deploy(session).unwrap();
+	for voter in [BOB, CHARLIE] {
+		assert_ok!(session.sandbox().mint_into(&TOKEN, &voter, AMOUNT));
+		session.set_actor(voter);
+		assert_ok!(register_voter(session));
+	}
+	dao
+}
+
+// Voting deposit tests.
+
+#[drink::test(sandbox = Pop)]
+fn deposit_voting_tokens_fails_without_runtime_approval(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	session.set_actor(BOB);
+	assert_eq!(deposit_voting_tokens(&mut session, AMOUNT), Err(DaoError::TokenTransferFailed));
+	// Approving less than the deposit is not enough either.
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &dao, AMOUNT / 2));
+	assert_eq!(deposit_voting_tokens(&mut session, AMOUNT), Err(DaoError::TokenTransferFailed));
+	assert_eq!(voting_deposit(&mut session, BOB), 0);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT);
+}
+
+#[drink::test(sandbox = Pop)]
+fn deposit_voting_tokens_fails_when_not_registered(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &ALICE, AMOUNT));
+	assert_ok!(session.sandbox().approve(&TOKEN, &ALICE, &dao, AMOUNT));
+	session.set_actor(ALICE);
+	assert_eq!(deposit_voting_tokens(&mut session, AMOUNT), Err(DaoError::VoterNotRegistered));
+}
+
+#[drink::test(sandbox = Pop)]
+fn deposit_and_withdraw_voting_tokens_work(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	let value = AMOUNT / 4;
+	session.set_actor(BOB);
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &dao, AMOUNT));
+	// Successfully deposit.
+	assert_ok!(deposit_voting_tokens(&mut session, AMOUNT));
+	assert_eq!(voting_deposit(&mut session, BOB), AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &dao), AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), 0);
+	// Deposits are not part of the treasury.
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, 0)]);
+	// Withdrawing more than the deposit fails.
+	assert_eq!(
+		withdraw_voting_tokens(&mut session, AMOUNT + 1),
+		Err(DaoError::InsufficientVotingDeposit)
+	);
+	// Successfully withdraw.
+	assert_ok!(withdraw_voting_tokens(&mut session, value));
+	assert_eq!(voting_deposit(&mut session, BOB), AMOUNT - value);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &dao), AMOUNT - value);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), value);
+}
+
+// Treasury proposal tests.
+
+#[drink::test(sandbox = Pop)]
+fn treasury_transfer_flow_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	let payout = AMOUNT / 2;
+	// Fund the treasury.
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &dao, AMOUNT));
+	// Both voters deposit, `BOB` with more weight than `CHARLIE`.
+	for (voter, deposit) in [(BOB, AMOUNT), (CHARLIE, AMOUNT / 2)] {
+		assert_ok!(session.sandbox().approve(&TOKEN, &voter, &dao, deposit));
+		session.set_actor(voter);
+		assert_ok!(deposit_voting_tokens(&mut session, deposit));
+	}
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, AMOUNT)]);
+	// Propose to pay `CHARLIE` from the treasury.
+	session.set_actor(BOB);
+	let proposal_id = create_treasury_proposal(&mut session, TOKEN, CHARLIE, payout).unwrap();
+	assert_ok!(submit_treasury_prevote(&mut session, proposal_id, true));
+	session.set_actor(CHARLIE);
+	assert_ok!(submit_treasury_prevote(&mut session, proposal_id, false));
+	// Prevotes are weighted by the deposits.
+	let proposal = get_treasury_proposal(&mut session, proposal_id).unwrap();
+	assert_eq!(proposal.prevote.aye_votes, vec![(BOB, AMOUNT)]);
+	assert_eq!(proposal.prevote.nay_votes, vec![(CHARLIE, AMOUNT / 2)]);
+	// Deposits stay locked and the proposal cannot execute until the prevote ends.
+	assert_eq!(withdraw_voting_tokens(&mut session, 1), Err(DaoError::VotingDepositLocked));
+	assert_eq!(
+		execute_treasury_proposal(&mut session, proposal_id),
+		Err(DaoError::PrevotePeriodIsNotEnded)
+	);
+	session.sandbox().build_blocks(PREVOTE_PERIOD + 1);
+	// Successfully execute.
+	assert_ok!(execute_treasury_proposal(&mut session, proposal_id));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT / 2 + payout);
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, AMOUNT - payout)]);
+	assert_eq!(
+		execute_treasury_proposal(&mut session, proposal_id),
+		Err(DaoError::ProposalAlreadyExecuted)
+	);
+	// Successfully withdraw the deposits once the prevote ended.
+	for (voter, deposit) in [(BOB, AMOUNT), (CHARLIE, AMOUNT / 2)] {
+		session.set_actor(voter);
+		assert_ok!(withdraw_voting_tokens(&mut session, deposit));
+		assert_eq!(voting_deposit(&mut session, voter), 0);
+	}
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &BOB), AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT + payout);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &dao), AMOUNT - payout);
+}
+
+#[drink::test(sandbox = Pop)]
+fn treasury_transfer_cannot_spend_voting_deposits(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &dao, AMOUNT / 4));
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &dao, AMOUNT));
+	session.set_actor(BOB);
+	assert_ok!(deposit_voting_tokens(&mut session, AMOUNT));
+	// The Dao holds enough tokens, but most of them are voting deposits.
+	let proposal_id = create_treasury_proposal(&mut session, TOKEN, CHARLIE, AMOUNT / 2).unwrap();
+	assert_ok!(submit_treasury_prevote(&mut session, proposal_id, true));
+	session.sandbox().build_blocks(PREVOTE_PERIOD + 1);
+	assert_eq!(
+		execute_treasury_proposal(&mut session, proposal_id),
+		Err(DaoError::InsufficientTreasury)
+	);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &dao), AMOUNT + AMOUNT / 4);
+}
+
+#[drink::test(sandbox = Pop)]
+fn create_track_token_proposal_fails_with_unknown_token(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy_with_voters(&mut session);
+	session.set_actor(BOB);
+	assert_eq!(
+		create_track_token_proposal(&mut session, TOKEN + 1),
+		Err(DaoError::TokenDoesNotExist)
+	);
+}
+
+// Stream tests.
+
+// Schedule releasing `AMOUNT` over 400 blocks from block 200, with a cliff of 100 blocks.
+const SCHEDULE: VestingSchedule = VestingSchedule { start: 200, cliff: 100, duration: 400, total: AMOUNT };
+
+// Fund the treasury with `funds` tokens and pass a proposal to stream `SCHEDULE` of them to
+// `CHARLIE`, returning the stream identifier.
+fn start_stream_to_charlie(session: &mut Session<Pop>, dao: &AccountId, funds: Balance) -> u32 {
+	assert_ok!(session.sandbox().mint_into(&TOKEN, dao, funds));
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, dao, AMOUNT));
+	session.set_actor(BOB);
+	assert_ok!(deposit_voting_tokens(session, AMOUNT));
+	let proposal_id = create_stream_proposal(session, Some(TOKEN), CHARLIE, SCHEDULE).unwrap();
+	pass_treasury_proposal(session, proposal_id);
+	assert_ok!(execute_treasury_proposal(session, proposal_id));
+	*streams_of(session, CHARLIE).last().unwrap()
+}
+
+// Prevote `BOB`'s approval of the proposal and wait for the prevote to end.
+fn pass_treasury_proposal(session: &mut Session<Pop>, proposal_id: u32) {
+	session.set_actor(BOB);
+	assert_ok!(submit_treasury_prevote(session, proposal_id, true));
+	session.sandbox().build_blocks(PREVOTE_PERIOD + 1);
+}
+
+#[drink::test(sandbox = Pop)]
+fn create_stream_proposal_fails_with_cliff_after_end(mut session: Session) {
+	let _ = env_logger::try_init();
+	deploy_with_voters(&mut session);
+	session.set_actor(BOB);
+	let schedule = VestingSchedule { cliff: SCHEDULE.duration + 1, ..SCHEDULE };
+	assert_eq!(
+		create_stream_proposal(&mut session, Some(TOKEN), CHARLIE, schedule),
+		Err(DaoError::InvalidVestingSchedule)
+	);
+}
+
+#[drink::test(sandbox = Pop)]
+fn stream_fails_without_enough_treasury(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	assert_ok!(session.sandbox().mint_into(&TOKEN, &dao, AMOUNT - 1));
+	assert_ok!(session.sandbox().approve(&TOKEN, &BOB, &dao, AMOUNT));
+	session.set_actor(BOB);
+	assert_ok!(deposit_voting_tokens(&mut session, AMOUNT));
+	let proposal_id = create_stream_proposal(&mut session, Some(TOKEN), CHARLIE, SCHEDULE).unwrap();
+	pass_treasury_proposal(&mut session, proposal_id);
+	assert_eq!(
+		execute_treasury_proposal(&mut session, proposal_id),
+		Err(DaoError::InsufficientTreasury)
+	);
+	assert_eq!(streams_of(&mut session, CHARLIE), vec![]);
+}
+
+#[drink::test(sandbox = Pop)]
+fn stream_reserves_its_schedule(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	start_stream_to_charlie(&mut session, &dao, AMOUNT * 2);
+	// The whole schedule is set aside from the treasury.
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, AMOUNT)]);
+	// Transfers and other streams cannot spend the reserved tokens.
+	let transfer_id = create_treasury_proposal(&mut session, TOKEN, BOB, AMOUNT + 1).unwrap();
+	let stream_id = create_stream_proposal(&mut session, Some(TOKEN), BOB, SCHEDULE).unwrap();
+	let schedule = VestingSchedule { total: AMOUNT + 1, ..SCHEDULE };
+	let oversized_stream_id = create_stream_proposal(&mut session, Some(TOKEN), BOB, schedule).unwrap();
+	for proposal_id in [transfer_id, stream_id, oversized_stream_id] {
+		assert_ok!(submit_treasury_prevote(&mut session, proposal_id, true));
+	}
+	session.sandbox().build_blocks(PREVOTE_PERIOD + 1);
+	assert_eq!(
+		execute_treasury_proposal(&mut session, transfer_id),
+		Err(DaoError::InsufficientTreasury)
+	);
+	assert_eq!(
+		execute_treasury_proposal(&mut session, oversized_stream_id),
+		Err(DaoError::InsufficientTreasury)
+	);
+	// A stream the remaining treasury covers can start.
+	assert_ok!(execute_treasury_proposal(&mut session, stream_id));
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, 0)]);
+}
+
+#[drink::test(sandbox = Pop)]
+fn claim_vested_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	let stream_id = start_stream_to_charlie(&mut session, &dao, AMOUNT);
+	session.set_actor(CHARLIE);
+	// Nothing vests before the cliff.
+	run_to_block(&mut session, SCHEDULE.start + SCHEDULE.cliff - 1);
+	assert_eq!(claimable(&mut session, stream_id), Ok(0));
+	assert_eq!(claim_vested(&mut session), Err(DaoError::NothingToClaim));
+	// The amount vested up to the cliff is released at once.
+	run_to_block(&mut session, SCHEDULE.start + SCHEDULE.cliff);
+	assert_eq!(claimable(&mut session, stream_id), Ok(AMOUNT / 4));
+	assert_eq!(claim_vested(&mut session), Ok(AMOUNT / 4));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT + AMOUNT / 4);
+	assert_eq!(claim_vested(&mut session), Err(DaoError::NothingToClaim));
+	// Claims are paid from the reserved tokens, not from the treasury.
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, 0)]);
+	// The rest vests linearly until the end of the schedule.
+	run_to_block(&mut session, SCHEDULE.start + SCHEDULE.duration / 2);
+	assert_eq!(claim_vested(&mut session), Ok(AMOUNT / 4));
+	run_to_block(&mut session, SCHEDULE.start + SCHEDULE.duration * 2);
+	assert_eq!(claim_vested(&mut session), Ok(AMOUNT / 2));
+	assert_eq!(get_stream(&mut session, stream_id).unwrap().claimed, AMOUNT);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT * 2);
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &dao), AMOUNT);
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, 0)]);
+}
+
+#[drink::test(sandbox = Pop)]
+fn cancel_stream_works(mut session: Session) {
+	let _ = env_logger::try_init();
+	let dao = deploy_with_voters(&mut session);
+	let stream_id = start_stream_to_charlie(&mut session, &dao, AMOUNT);
+	// Claim part of the stream before it is cancelled.
+	run_to_block(&mut session, SCHEDULE.start + SCHEDULE.cliff);
+	session.set_actor(CHARLIE);
+	assert_eq!(claim_vested(&mut session), Ok(AMOUNT / 4));
+	// Successfully cancel.
+	session.set_actor(BOB);
+	let proposal_id = create_cancel_stream_proposal(&mut session, stream_id).unwrap();
+	pass_treasury_proposal(&mut session, proposal_id);
+	assert_ok!(execute_treasury_proposal(&mut session, proposal_id));
+	let stream = get_stream(&mut session, stream_id).unwrap();
+	let cancelled_at = stream.cancelled_at.unwrap();
+	let vested = SCHEDULE.vested_at(cancelled_at);
+	// The unvested part of the schedule returns to the treasury.
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, AMOUNT - vested)]);
+	// Vesting stops at the cancellation, and the amount vested until then stays claimable.
+	run_to_block(&mut session, SCHEDULE.start + SCHEDULE.duration);
+	assert_eq!(claimable(&mut session, stream_id), Ok(vested - AMOUNT / 4));
+	session.set_actor(CHARLIE);
+	assert_eq!(claim_vested(&mut session), Ok(vested - AMOUNT / 4));
+	assert_eq!(claim_vested(&mut session), Err(DaoError::NothingToClaim));
+	assert_eq!(session.sandbox().balance_of(&TOKEN, &CHARLIE), AMOUNT + vested);
+	assert_eq!(treasury_token_balances(&mut session), vec![(TOKEN, AMOUNT - vested)]);
+	// A stream cannot be cancelled twice.
+	session.set_actor(BOB);
+	let proposal_id = create_cancel_stream_proposal(&mut session, stream_id).unwrap();
+	pass_treasury_proposal(&mut session, proposal_id);
+	assert_eq!(
+		execute_treasury_proposal(&mut session, proposal_id),
+		Err(DaoError::StreamAlreadyCancelled)
+	);
+	// Unknown streams cannot be cancelled.
+	assert_eq!(
+		create_cancel_stream_proposal(&mut session, stream_id + 1),
+		Err(DaoError::StreamDoesNotExist)
+	);
+}
+
+// Deploy the Dao with the governance token `TOKEN`.
+fn deploy(session: &mut Session<Pop>) -> Result<AccountId, DaoError> {
+	drink::deploy::<Pop, DaoError>(
+		session,
+		// The local contract (i.e. `challenge-7-contract`).
+		BundleProvider::local().unwrap(),
+		"new",
+		vec![format!("{:?}", "Dao"), SUPERDAO.to_string(), TOKEN.to_string()],
+		NO_SALT,
+		None,
+	)
+}
+
+// A set of helper methods to test the contract calls.
+
+fn register_voter(session: &mut Session<Pop>) -> Result<(), DaoError> {
+	call::<Pop, (), DaoError>(session, "register_voter", vec![], None)
+}
+
+fn deposit_voting_tokens(session: &mut Session<Pop>, amount: Balance) -> Result<(), DaoError> {
+	call::<Pop, (), DaoError>(session, "deposit_voting_tokens", vec![amount.to_string()], None)
+}
+
+fn withdraw_voting_tokens(session: &mut Session<Pop>, amount: Balance) -> Result<(), DaoError> {
+	call::<Pop, (), DaoError>(session, "withdraw_voting_tokens", vec![amount.to_string()], None)
+}
+
+fn voting_deposit(session: &mut Session<Pop>, voter: AccountId) -> Balance {
+	call::<Pop, Balance, DaoError>(session, "voting_deposit", vec![voter.to_string()], None).unwrap()
+}
+
+fn treasury_token_balances(session: &mut Session<Pop>) -> Vec<(TokenId, Balance)> {
+	call::<Pop, Vec<(TokenId, Balance)>, DaoError>(session, "treasury_token_balances", vec![], None)
+		.unwrap()
+}
+
+fn create_treasury_proposal(
+	session: &mut Session<Pop>,
+	token: TokenId,
+	beneficiary: AccountId,
+	amount: Balance,
+) -> Result<u32, DaoError> {
+	call::<Pop, u32, DaoError>(
+		session,
+		"create_treasury_proposal",
+		vec![token.to_string(), beneficiary.to_string(), amount.to_string()],
+		None,
+	)
+}
+
+fn create_track_token_proposal(session: &mut Session<Pop>, token: TokenId) -> Result<u32, DaoError> {
+	call::<Pop, u32, DaoError>(session, "create_track_token_proposal", vec![token.to_string()], None)
+}
+
+fn submit_treasury_prevote(
+	session: &mut Session<Pop>,
+	proposal_id: u32,
+	approved: bool,
+) -> Result<(), DaoError> {
+	call::<Pop, (), DaoError>(
+		session,
+		"submit_treasury_prevote",
+		vec![proposal_id.to_string(), approved.to_string()],
+		None,
+	)
+}
+
+fn execute_treasury_proposal(session: &mut Session<Pop>, proposal_id: u32) -> Result<(), DaoError> {
+	call::<Pop, (), DaoError>(session, "execute_treasury_proposal", vec![proposal_id.to_string()], None)
+}
+
+fn get_treasury_proposal(
+	session: &mut Session<Pop>,
+	proposal_id: u32,
+) -> Result<TreasuryProposal, DaoError> {
+	call::<Pop, TreasuryProposal, DaoError>(
+		session,
+		"get_treasury_proposal",
+		vec![proposal_id.to_string()],
+		None,
+	)
+}
+
+fn create_stream_proposal(
+	session: &mut Session<Pop>,
+	token: Option<TokenId>,
+	beneficiary: AccountId,
+	schedule: VestingSchedule,
+) -> Result<u32, DaoError> {
+	let token = match token {
+		Some(token) => format!("Some({})", token),
+		None => "None".to_string(),
+	};
+	call::<Pop, u32, DaoError>(
+		session,
+		"create_stream_proposal",
+		vec![token, beneficiary.to_string(), schedule_arg(schedule)],
+		None,
+	)
+}
+
+fn create_cancel_stream_proposal(session: &mut Session<Pop>, stream_id: u32) -> Result<u32, DaoError> {
+	call::<Pop, u32, DaoError>(session, "create_cancel_stream_proposal", vec![stream_id.to_string()], None)
+}
+
+fn claim_vested(session: &mut Session<Pop>) -> Result<Balance, DaoError> {
+	call::<Pop, Balance, DaoError>(session, "claim_vested", vec![], None)
+}
+
+fn claimable(session: &mut Session<Pop>, stream_id: u32) -> Result<Balance, DaoError> {
+	call::<Pop, Balance, DaoError>(session, "claimable", vec![stream_id.to_string()], None)
+}
+
+fn get_stream(session: &mut Session<Pop>, stream_id: u32) -> Result<Stream, DaoError> {
+	call::<Pop, Stream, DaoError>(session, "get_stream", vec![stream_id.to_string()], None)
+}
+
+fn streams_of(session: &mut Session<Pop>, beneficiary: AccountId) -> Vec<u32> {
+	call::<Pop, Vec<u32>, DaoError>(session, "streams_of", vec![beneficiary.to_string()], None).unwrap()
+}
+
+// Formats a schedule as a contract call argument.
+fn schedule_arg(schedule: VestingSchedule) -> String {
+	format!(
+		"VestingSchedule {{ start: {}, cliff: {}, duration: {}, total: {} }}",
+		schedule.start, schedule.cliff, schedule.duration, schedule.total
+	)
+}
+
+// Build blocks until the chain reaches block `n`.
+fn run_to_block(session: &mut Session<Pop>, n: u32) {
+	let now = session.sandbox().block_number();
+	session.sandbox().build_blocks(n.saturating_sub(now));
+}
@@ -0,0 +1,1022 @@
+use ink::{
+    prelude::{string::String, vec, vec::Vec},
+    xcm::prelude::*,
+};
+
+use crate::{AccountId, Balance, DaoError};
+
+/// Asset Hub, the default destination of cross-chain proposals.
+pub const ASSET_HUB: Chain = Chain::Parachain(1000);
+
+/// Parachain id of Pop Network, where the Superdao and its member Daos are deployed.
+pub const POP_PARA_ID: u32 = 4001;
+
+/// Maximum number of instructions in an XCM program, including nested ones.
+pub const MAX_PROGRAM_LENGTH: usize = 16;
+
+/// Maximum size of an encoded call dispatched by a `Transact`.
+pub const MAX_CALL_LEN: usize = 1024;
+
+/// Maximum length in bytes of a proposal's label.
+pub const MAX_LABEL_LEN: usize = 64;
+
+/// Reference time executed in one second, the unit fee rates are expressed in.
+pub const REF_TIME_PER_SECOND: u64 = 1_000_000_000_000;
+
+/// Default limit on the total weight of the `Transact`s in a program the Dao executes on Pop
+/// Network itself.
+pub const LOCAL_WEIGHT_LIMIT: Weight = Weight::from_parts(REF_TIME_PER_SECOND / 4, 1024 * 1024);
+
+// XCM types such as `Location` do not implement `StorageLayout`, so stored types holding them are
+// laid out as a single leaf.
+macro_rules! leaf_storage_layout {
+    ($($ty:ty),*) => {$(
+        #[cfg(feature = "std")]
+        impl ink::storage::traits::StorageLayout for $ty {
+            fn layout(key: &ink::primitives::Key) -> ink::metadata::layout::Layout {
+                use ink::metadata::layout::{Layout, LayoutKey, LeafLayout};
+                Layout::Leaf(LeafLayout::from_key::<Self>(LayoutKey::from(key)))
+            }
+        }
+    )*};
+}
+
+leaf_storage_layout!(DestinationConfig, ForwardedProposal, LocalProposal);
+
+/// A chain that cross-chain proposals can be sent to, seen from the Superdao's parachain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum Chain {
+    /// The relay chain.
+    Relay,
+    /// A sibling parachain with the given id.
+    Parachain(u32),
+}
+
+impl Chain {
+    /// Returns the XCM location of the chain.
+    pub fn location(&self) -> Location {
+        match self {
+            Chain::Relay => Location::parent(),
+            Chain::Parachain(id) => Location::new(1, [Parachain(*id)]),
+        }
+    }
+
+    /// Returns the location of `account` on Pop Network, as seen from this chain.
+    pub fn location_of(&self, account: AccountId) -> Location {
+        let parents = match self {
+            Chain::Relay => 0,
+            Chain::Parachain(_) => 1,
+        };
+        Location::new(
+            parents,
+            [Parachain(POP_PARA_ID), AccountId32 { network: None, id: *account.as_ref() }],
+        )
+    }
+
+    /// Returns the chain `location`, as seen from this chain, points into. Accounts and assets
+    /// resolve to the chain holding them.
+    pub fn resolve(&self, location: &Location) -> Result<Chain, DaoError> {
+        match (self, location.parent_count(), location.interior().as_slice()) {
+            (Chain::Relay, 0, [Parachain(id), ..]) | (Chain::Parachain(_), 1, [Parachain(id), ..]) => {
+                Ok(Chain::Parachain(*id))
+            },
+            (_, 0, _) => Ok(*self),
+            (Chain::Parachain(_), 1, _) => Ok(Chain::Relay),
+            _ => Err(DaoError::UnsupportedDestination),
+        }
+    }
+}
+
+impl TryFrom<&Location> for Chain {
+    type Error = DaoError;
+
+    fn try_from(location: &Location) -> Result<Self, Self::Error> {
+        if location.parent_count() != 1 {
+            return Err(DaoError::UnsupportedDestination);
+        }
+        match location.interior().as_slice() {
+            [] => Ok(Chain::Relay),
+            [Parachain(id)] => Ok(Chain::Parachain(*id)),
+            _ => Err(DaoError::UnsupportedDestination),
+        }
+    }
+}
+
+/// Settings of a destination that cross-chain proposals are allowed to target.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct DestinationConfig {
+    /// Asset used to pay for execution when a proposal does not specify one.
+    pub fee_asset: Location,
+    /// Weights and fee rates used to estimate and limit the cost of proposals.
+    pub fees: FeeTable,
+    /// `(pallet_index, call_index)` pairs that a `Transact` may dispatch, or `None` to allow any call.
+    pub allowed_calls: Option<Vec<(u8, u8)>>,
+    /// Origin kinds that a `Transact` may dispatch its call with.
+    pub allowed_origin_kinds: Vec<OriginKind>,
+}
+
+impl DestinationConfig {
+    /// Returns the config of a newly allowed destination, which places no limits on proposals
+    /// other than dispatching calls from the Superdao's sovereign account.
+    pub fn new(fee_asset: Location) -> Self {
+        Self {
+            fee_asset,
+            fees: FeeTable::default(),
+            allowed_calls: None,
+            allowed_origin_kinds: vec![OriginKind::SovereignAccount],
+        }
+    }
+
+    /// Returns the config of programs the Dao executes on Pop Network itself, whose `Transact`s
+    /// may require at most `LOCAL_WEIGHT_LIMIT` in total.
+    pub fn local() -> Self {
+        Self {
+            fees: FeeTable { max_weight: LOCAL_WEIGHT_LIMIT, ..FeeTable::default() },
+            ..Self::new(Location::parent())
+        }
+    }
+
+    /// Checks that every `Transact` in `program`, including nested ones, stays within the weight
+    /// limit, uses an allowed origin kind and dispatches a non-empty, allowed call of at most
+    /// `MAX_CALL_LEN` bytes.
+    pub fn validate(&self, program: &[ProposalInstruction]) -> Result<(), DaoError> {
+        for instruction in program {
+            if let ProposalInstruction::Transact { origin_kind, ref_time, proof_size, call } =
+                instruction
+            {
+                if Weight::from_parts(*ref_time, *proof_size).any_gt(self.fees.max_weight) {
+                    return Err(DaoError::WeightLimitExceeded);
+                }
+                if !self.allowed_origin_kinds.contains(origin_kind) {
+                    return Err(DaoError::OriginKindNotAllowed);
+                }
+                self.ensure_call_allowed(call)?;
+            }
+            if let Some(nested) = instruction.nested() {
+                self.validate(nested)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Validates a program executed rather than sent, which also requires the `Transact`s run on
+    /// this chain to stay within the weight limit in total.
+    pub fn validate_local(&self, program: &[ProposalInstruction]) -> Result<(), DaoError> {
+        self.validate(program)?;
+        if transact_weight(program).any_gt(self.fees.max_weight) {
+            return Err(DaoError::WeightLimitExceeded);
+        }
+        Ok(())
+    }
+
+    fn ensure_call_allowed(&self, call: &[u8]) -> Result<(), DaoError> {
+        if call.is_empty() {
+            return Err(DaoError::EmptyCall);
+        }
+        if call.len() > MAX_CALL_LEN {
+            return Err(DaoError::CallTooLarge);
+        }
+        if let Some(allowed_calls) = &self.allowed_calls {
+            match call {
+                [pallet_index, call_index, ..]
+                    if allowed_calls.contains(&(*pallet_index, *call_index)) => {},
+                _ => return Err(DaoError::CallNotAllowed),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Weights and fee rates of a destination, maintained by governance.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct FeeTable {
+    /// Weight of dispatching an empty call.
+    pub base_weight: Weight,
+    /// Weight added per byte of the encoded call.
+    pub weight_per_byte: Weight,
+    /// Fee charged on top of the weight-based fee, covering the rest of the XCM program.
+    pub base_fee: Balance,
+    /// Fee charged per second of reference time, in units of the fee asset.
+    pub fee_per_second: Balance,
+    /// Maximum weight a `Transact` may require.
+    pub max_weight: Weight,
+}
+
+impl Default for FeeTable {
+    fn default() -> Self {
+        Self {
+            base_weight: Weight::zero(),
+            weight_per_byte: Weight::zero(),
+            base_fee: 0,
+            fee_per_second: 0,
+            max_weight: Weight::MAX,
+        }
+    }
+}
+
+impl FeeTable {
+    /// Returns the recommended weight and fee to dispatch an encoded call of `call_len` bytes.
+    pub fn estimate(&self, call_len: usize) -> (Weight, Balance) {
+        let weight = self
+            .base_weight
+            .saturating_add(self.weight_per_byte.saturating_mul(call_len as u64));
+        let fee = self.base_fee.saturating_add(
+            Balance::from(weight.ref_time()).saturating_mul(self.fee_per_second) /
+                Balance::from(REF_TIME_PER_SECOND),
+        );
+        (weight, fee)
+    }
+}
+
+/// An XCM instruction that can be part of a cross-chain proposal's program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum ProposalInstruction {
+    /// Withdraw `amount` of `asset` from the Superdao's sovereign account into holding.
+    WithdrawAsset { asset: Location, amount: Balance },
+    /// Pay for execution with up to `amount` of `asset` from holding.
+    BuyExecution { asset: Location, amount: Balance },
+    /// Dispatch the encoded `call` with the given origin kind and maximum weight.
+    Transact { origin_kind: OriginKind, ref_time: u64, proof_size: u64, call: Vec<u8> },
+    /// Deposit up to `max_assets` assets from holding into `beneficiary`.
+    DepositAsset { max_assets: u32, beneficiary: Location },
+    /// Transfer `amount` of `asset` from the Superdao's sovereign account to `beneficiary`.
+    TransferAsset { asset: Location, amount: Balance, beneficiary: Location },
+    /// Report the outcome of the last `Transact` to `destination` under `query_id`.
+    ReportTransactStatus { destination: Location, query_id: u64, ref_time: u64, proof_size: u64 },
+    /// Report the program's error, if any, to `destination` under `query_id`.
+    ReportError { destination: Location, query_id: u64, ref_time: u64, proof_size: u64 },
+    /// Deposit up to `max_assets` assets from holding into `dest`'s sovereign account, with this
+    /// chain as their reserve, and run `xcm` on `dest`.
+    DepositReserveAsset { max_assets: u32, dest: Location, xcm: Vec<ProposalInstruction> },
+    /// Teleport up to `max_assets` assets from holding to `dest` and run `xcm` on `dest`.
+    InitiateTeleport { max_assets: u32, dest: Location, xcm: Vec<ProposalInstruction> },
+    /// Return unused execution fees to holding.
+    RefundSurplus,
+    /// Run the given instructions after the program, whether or not it succeeded.
+    SetAppendix(Vec<ProposalInstruction>),
+    /// Run the given instructions if the program fails.
+    SetErrorHandler(Vec<ProposalInstruction>),
+}
+
+impl ProposalInstruction {
+    fn nested(&self) -> Option<&[ProposalInstruction]> {
+        match self {
+            Self::DepositReserveAsset { xcm, .. } | Self::InitiateTeleport { xcm, .. } => Some(xcm),
+            Self::SetAppendix(xcm) | Self::SetErrorHandler(xcm) => Some(xcm),
+            _ => None,
+        }
+    }
+
+    fn to_instruction(&self) -> Instruction<()> {
+        match self {
+            Self::WithdrawAsset { asset, amount } => {
+                Instruction::WithdrawAsset((asset.clone(), *amount).into())
+            },
+            Self::BuyExecution { asset, amount } => Instruction::BuyExecution {
+                fees: (asset.clone(), *amount).into(),
+                weight_limit: Unlimited,
+            },
+            Self::Transact { origin_kind, ref_time, proof_size, call } => Instruction::Transact {
+                origin_kind: *origin_kind,
+                require_weight_at_most: Weight::from_parts(*ref_time, *proof_size),
+                call: call.clone().into(),
+            },
+            Self::DepositAsset { max_assets, beneficiary } => Instruction::DepositAsset {
+                assets: AllCounted(*max_assets).into(),
+                beneficiary: beneficiary.clone(),
+            },
+            Self::TransferAsset { asset, amount, beneficiary } => Instruction::TransferAsset {
+                assets: (asset.clone(), *amount).into(),
+                beneficiary: beneficiary.clone(),
+            },
+            Self::ReportTransactStatus { destination, query_id, ref_time, proof_size } => {
+                Instruction::ReportTransactStatus(QueryResponseInfo {
+                    destination: destination.clone(),
+                    query_id: *query_id,
+                    max_weight: Weight::from_parts(*ref_time, *proof_size),
+                })
+            },
+            Self::ReportError { destination, query_id, ref_time, proof_size } => {
+                Instruction::ReportError(QueryResponseInfo {
+                    destination: destination.clone(),
+                    query_id: *query_id,
+                    max_weight: Weight::from_parts(*ref_time, *proof_size),
+                })
+            },
+            Self::DepositReserveAsset { max_assets, dest, xcm } => {
+                Instruction::DepositReserveAsset {
+                    assets: AllCounted(*max_assets).into(),
+                    dest: dest.clone(),
+                    xcm: to_xcm(xcm),
+                }
+            },
+            Self::InitiateTeleport { max_assets, dest, xcm } => Instruction::InitiateTeleport {
+                assets: AllCounted(*max_assets).into(),
+                dest: dest.clone(),
+                xcm: to_xcm(xcm),
+            },
+            Self::RefundSurplus => Instruction::RefundSurplus,
+            Self::SetAppendix(handler) => Instruction::SetAppendix(to_xcm(handler)),
+            Self::SetErrorHandler(handler) => Instruction::SetErrorHandler(to_xcm(handler)),
+        }
+    }
+}
+
+/// Validates a cross-chain proposal's program and assembles it into an XCM message.
+///
+/// The program must start by withdrawing an asset and buying execution with it, may not exceed
+/// `MAX_PROGRAM_LENGTH` instructions and may only nest programs one level deep.
+pub fn build_xcm_program(program: &[ProposalInstruction]) -> Result<Xcm<()>, DaoError> {
+    if program.is_empty() {
+        return Err(DaoError::EmptyXcmProgram);
+    }
+    if !matches!(
+        program,
+        [ProposalInstruction::WithdrawAsset { .. }, ProposalInstruction::BuyExecution { .. }, ..]
+    ) {
+        return Err(DaoError::XcmProgramNotPaid);
+    }
+    let mut length = program.len();
+    for instruction in program {
+        if let Some(nested) = instruction.nested() {
+            if nested.iter().any(|i| i.nested().is_some()) {
+                return Err(DaoError::NestedXcmProgram);
+            }
+            length += nested.len();
+        }
+    }
+    if length > MAX_PROGRAM_LENGTH {
+        return Err(DaoError::XcmProgramTooLong);
+    }
+    Ok(to_xcm(program))
+}
+
+/// Returns every chain that `program`, run on `chain`, deposits or transfers assets to, reports to
+/// or runs a nested program on.
+pub fn reached_chains(chain: Chain, program: &[ProposalInstruction]) -> Result<Vec<Chain>, DaoError> {
+    let mut chains = Vec::new();
+    for instruction in program {
+        match instruction {
+            ProposalInstruction::DepositAsset { beneficiary, .. } |
+            ProposalInstruction::TransferAsset { beneficiary, .. } => chains.push(chain.resolve(beneficiary)?),
+            ProposalInstruction::ReportTransactStatus { destination, .. } |
+            ProposalInstruction::ReportError { destination, .. } => chains.push(chain.resolve(destination)?),
+            ProposalInstruction::DepositReserveAsset { dest, xcm, .. } |
+            ProposalInstruction::InitiateTeleport { dest, xcm, .. } => {
+                let dest = chain.resolve(dest)?;
+                chains.push(dest);
+                chains.append(&mut reached_chains(dest, xcm)?);
+            },
+            ProposalInstruction::SetAppendix(xcm) | ProposalInstruction::SetErrorHandler(xcm) => {
+                chains.append(&mut reached_chains(chain, xcm)?);
+            },
+            _ => {},
+        }
+    }
+    Ok(chains)
+}
+
+// Total weight the `Transact`s of `program` may require on the chain it runs on. Programs run on
+// other chains through `DepositReserveAsset` or `InitiateTeleport` are left out.
+fn transact_weight(program: &[ProposalInstruction]) -> Weight {
+    program.iter().fold(Weight::zero(), |total, instruction| match instruction {
+        ProposalInstruction::Transact { ref_time, proof_size, .. } => {
+            total.saturating_add(Weight::from_parts(*ref_time, *proof_size))
+        },
+        ProposalInstruction::SetAppendix(xcm) | ProposalInstruction::SetErrorHandler(xcm) => {
+            total.saturating_add(transact_weight(xcm))
+        },
+        _ => total,
+    })
+}
+
+/// Outcome of a cross-chain proposal's execution on its destination.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub enum ExecutionStatus {
+    /// No final report has been received yet.
+    Pending,
+    /// The program and its `Transact` succeeded.
+    Succeeded,
+    /// The program or its `Transact` failed.
+    Failed,
+}
+
+/// A cross-chain proposal the Dao forwarded to the Superdao.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct ForwardedProposal {
+    /// Label given by the proposer.
+    pub label: Option<String>,
+    /// Chain the proposal executes on.
+    pub dest: Chain,
+    /// Asset paying for execution.
+    pub fee_asset: Location,
+    /// Maximum amount of `fee_asset` paid for execution.
+    pub fee_max: Balance,
+    /// Maximum weight of the dispatched call.
+    pub weight: Weight,
+    /// Leading `(pallet_index, call_index)` of the dispatched call.
+    pub call_index: Option<(u8, u8)>,
+    /// Query id the destination reports the execution outcome under.
+    pub query_id: u64,
+    /// Execution outcome reported so far.
+    pub status: ExecutionStatus,
+}
+
+/// Summary of a forwarded cross-chain proposal, describing what voters are approving.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct ProposalSummary {
+    /// Label given by the proposer.
+    pub label: Option<String>,
+    /// Location of the chain the proposal executes on.
+    pub dest: Location,
+    /// Asset paying for execution.
+    pub fee_asset: Location,
+    /// Maximum amount of `fee_asset` paid for execution.
+    pub fee_max: Balance,
+    /// Maximum weight of the dispatched call.
+    pub weight: Weight,
+    /// Index of the pallet the dispatched call belongs to.
+    pub pallet_index: Option<u8>,
+    /// Index of the dispatched call within its pallet.
+    pub call_index: Option<u8>,
+    /// Execution outcome reported so far.
+    pub status: ExecutionStatus,
+}
+
+impl ForwardedProposal {
+    /// Returns the record of a proposal dispatching `call` on `dest`, pending execution.
+    ///
+    /// Fails if `label` is longer than `MAX_LABEL_LEN` bytes.
+    pub fn new(
+        label: Option<String>,
+        dest: Chain,
+        fee_asset: Location,
+        fee_max: Balance,
+        weight: Weight,
+        call: &[u8],
+        query_id: u64,
+    ) -> Result<Self, DaoError> {
+        if label.as_ref().is_some_and(|label| label.len() > MAX_LABEL_LEN) {
+            return Err(DaoError::LabelTooLong);
+        }
+        let call_index = match call {
+            [pallet_index, call_index, ..] => Some((*pallet_index, *call_index)),
+            _ => None,
+        };
+        Ok(Self {
+            label,
+            dest,
+            fee_asset,
+            fee_max,
+            weight,
+            call_index,
+            query_id,
+            status: ExecutionStatus::Pending,
+        })
+    }
+
+    /// Returns the record of a proposal running `program` on `dest`, pending execution. The
+    /// proposal is described by the execution the program buys and its first `Transact`, if any.
+    ///
+    /// Fails if `label` is longer than `MAX_LABEL_LEN` bytes or the program buys no execution.
+    pub fn from_program(
+        label: Option<String>,
+        dest: Chain,
+        program: &[ProposalInstruction],
+        query_id: u64,
+    ) -> Result<Self, DaoError> {
+        let (fee_asset, fee_max) = program
+            .iter()
+            .find_map(|instruction| match instruction {
+                ProposalInstruction::BuyExecution { asset, amount } => Some((asset.clone(), *amount)),
+                _ => None,
+            })
+            .ok_or(DaoError::XcmProgramNotPaid)?;
+        let (weight, call) = program
+            .iter()
+            .find_map(|instruction| match instruction {
+                ProposalInstruction::Transact { ref_time, proof_size, call, .. } => {
+                    Some((Weight::from_parts(*ref_time, *proof_size), call.as_slice()))
+                },
+                _ => None,
+            })
+            .unwrap_or((Weight::zero(), &[]));
+        Self::new(label, dest, fee_asset, fee_max, weight, call, query_id)
+    }
+
+    /// Returns a summary of the proposal for voters.
+    pub fn summary(&self) -> ProposalSummary {
+        ProposalSummary {
+            label: self.label.clone(),
+            dest: self.dest.location(),
+            fee_asset: self.fee_asset.clone(),
+            fee_max: self.fee_max,
+            weight: self.weight,
+            pallet_index: self.call_index.map(|(pallet_index, _)| pallet_index),
+            call_index: self.call_index.map(|(_, call_index)| call_index),
+            status: self.status,
+        }
+    }
+
+    /// Applies a query response reported by the destination to the execution status.
+    ///
+    /// The `Transact` status arrives first and only marks failures. The program's error report
+    /// runs in the appendix and is final. Returns whether the response was the final one.
+    pub fn record_response(&mut self, response: &Response) -> Result<bool, DaoError> {
+        match response {
+            Response::DispatchResult(MaybeErrorCode::Success) => Ok(false),
+            Response::DispatchResult(_) => {
+                self.status = ExecutionStatus::Failed;
+                Ok(false)
+            },
+            Response::ExecutionResult(None) => {
+                if self.status == ExecutionStatus::Pending {
+                    self.status = ExecutionStatus::Succeeded;
+                }
+                Ok(true)
+            },
+            Response::ExecutionResult(Some(_)) => {
+                self.status = ExecutionStatus::Failed;
+                Ok(true)
+            },
+            _ => Err(DaoError::UnexpectedResponse),
+        }
+    }
+}
+
+/// An XCM program proposed to and voted on by the Dao's own members, which the Dao sends to
+/// `dest` itself once passed, or executes on Pop Network if there is no `dest`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+pub struct LocalProposal {
+    /// Chain the program is sent to, if any.
+    pub dest: Option<Chain>,
+    /// Program to send or execute.
+    pub program: Vec<ProposalInstruction>,
+    /// Members who voted for the proposal.
+    pub ayes: Vec<AccountId>,
+    /// Members who voted against the proposal.
+    pub nays: Vec<AccountId>,
+    /// Whether the program has been sent or executed.
+    pub executed: bool,
+}
+
+impl LocalProposal {
+    /// Returns whether `voter` has voted on the proposal.
+    pub fn has_voted(&self, voter: &AccountId) -> bool {
+        self.ayes.contains(voter) || self.nays.contains(voter)
+    }
+
+    /// Returns whether more than half of `member_count` members voted for the proposal.
+    ///
+    /// Only the votes of accounts for which `is_member` holds are counted, so that members who left
+    /// after voting do not carry the proposal.
+    pub fn is_passed(&self, member_count: u32, is_member: impl Fn(&AccountId) -> bool) -> bool {
+        let ayes = self.ayes.iter().filter(|voter| is_member(voter)).count();
+        (ayes as u64).saturating_mul(2) > u64::from(member_count)
+    }
+}
+
+/// Returns the program paying `amount` of `asset` from the Superdao's sovereign account on Asset
+/// Hub to `beneficiary` on `dest`. Fees of up to `fee_max` per chain are taken from the transferred
+/// amount.
+///
+/// `asset` and `beneficiary` are expressed relative to Asset Hub and `dest` respectively. DOT is
+/// teleported to the relay chain, which accepts no other asset, and any other transfer leaving
+/// Asset Hub uses it as the reserve.
+/// The assets stay in holding after paying for execution, so reserve transfers use
+/// `DepositReserveAsset`, the holding-based counterpart of `TransferReserveAsset`.
+pub fn transfer_program(
+    dest: Chain,
+    beneficiary: Location,
+    asset: Location,
+    amount: Balance,
+    fee_max: Balance,
+) -> Result<Vec<ProposalInstruction>, DaoError> {
+    // The relay chain only holds its native token, which Asset Hub teleports to it.
+    if dest == Chain::Relay && asset != Location::parent() {
+        return Err(DaoError::UnsupportedAsset);
+    }
+    let hops = if dest == ASSET_HUB { 1 } else { 2 };
+    if fee_max.saturating_mul(hops) > amount {
+        return Err(DaoError::FeeExceedsAmount);
+    }
+    let mut program = vec![
+        ProposalInstruction::WithdrawAsset { asset: asset.clone(), amount },
+        ProposalInstruction::BuyExecution { asset: asset.clone(), amount: fee_max },
+    ];
+    if dest == ASSET_HUB {
+        program.push(ProposalInstruction::DepositAsset { max_assets: 1, beneficiary });
+        return Ok(program);
+    }
+    let remote = vec![
+        ProposalInstruction::BuyExecution { asset: asset_seen_from(dest, &asset)?, amount: fee_max },
+        ProposalInstruction::DepositAsset { max_assets: 1, beneficiary },
+    ];
+    if dest == Chain::Relay {
+        program.push(ProposalInstruction::InitiateTeleport {
+            max_assets: 1,
+            dest: dest.location(),
+            xcm: remote,
+        });
+    } else {
+        program.push(ProposalInstruction::DepositReserveAsset {
+            max_assets: 1,
+            dest: dest.location(),
+            xcm: remote,
+        });
+    }
+    Ok(program)
+}
+
+/// Returns the program dispatching `call` with `origin_kind` and up to `weight` on the chain it is
+/// sent to, paying up to `fee_max` of `fee_asset` for execution.
+///
+/// The call's status is reported to `report_to` under `query_id`. The appendix runs whether or not
+/// the program fails, so that errors are reported too and unspent fees are always refunded to
+/// `refund_to` rather than trapped.
+#[allow(clippy::too_many_arguments)]
+pub fn transact_program(
+    fee_asset: Location,
+    fee_max: Balance,
+    origin_kind: OriginKind,
+    weight: Weight,
+    call: Vec<u8>,
+    report_to: Location,
+    query_id: u64,
+    refund_to: Location,
+) -> Vec<ProposalInstruction> {
+    vec![
+        ProposalInstruction::WithdrawAsset { asset: fee_asset.clone(), amount: fee_max },
+        ProposalInstruction::BuyExecution { asset: fee_asset, amount: fee_max },
+        ProposalInstruction::SetAppendix(vec![
+            ProposalInstruction::ReportError {
+                destination: report_to.clone(),
+                query_id,
+                ref_time: 0,
+                proof_size: 0,
+            },
+            ProposalInstruction::RefundSurplus,
+            ProposalInstruction::DepositAsset { max_assets: 1, beneficiary: refund_to },
+        ]),
+        ProposalInstruction::Transact {
+            origin_kind,
+            ref_time: weight.ref_time(),
+            proof_size: weight.proof_size(),
+            call,
+        },
+        ProposalInstruction::ReportTransactStatus {
+            destination: report_to,
+            query_id,
+            ref_time: 0,
+            proof_size: 0,
+        },
+    ]
+}
+
+// Re-expresses `asset`, given relative to Asset Hub, relative to `chain`.
+fn asset_seen_from(chain: Chain, asset: &Location) -> Result<Location, DaoError> {
+    let parents = match chain {
+        Chain::Relay => 0,
+        Chain::Parachain(_) => 1,
+    };
+    let interior = match asset.parent_count() {
+        0 => asset
+            .interior()
+            .clone()
+            .pushed_front_with(Parachain(1000))
+            .map_err(|_| DaoError::UnsupportedAsset)?,
+        1 => asset.interior().clone(),
+        _ => return Err(DaoError::UnsupportedAsset),
+    };
+    Ok(Location::new(parents, interior))
+}
+
+fn to_xcm(program: &[ProposalInstruction]) -> Xcm<()> {
+    Xcm(program.iter().map(ProposalInstruction::to_instruction).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ink::prelude::vec;
+
+    fn paid(mut rest: Vec<ProposalInstruction>) -> Vec<ProposalInstruction> {
+        let mut program = vec![
+            ProposalInstruction::WithdrawAsset { asset: Location::parent(), amount: 100 },
+            ProposalInstruction::BuyExecution { asset: Location::parent(), amount: 100 },
+        ];
+        program.append(&mut rest);
+        program
+    }
+
+    #[test]
+    fn test_build_xcm_program() {
+        let program = paid(vec![ProposalInstruction::RefundSurplus]);
+        let message = build_xcm_program(&program).unwrap();
+        assert_eq!(message.len(), 3);
+        assert!(matches!(message.0[2], Instruction::RefundSurplus));
+
+        assert_eq!(build_xcm_program(&[]), Err(DaoError::EmptyXcmProgram));
+        assert_eq!(
+            build_xcm_program(&[ProposalInstruction::RefundSurplus]),
+            Err(DaoError::XcmProgramNotPaid)
+        );
+    }
+
+    #[test]
+    fn test_build_xcm_program_limits() {
+        let nested = paid(vec![ProposalInstruction::SetAppendix(vec![
+            ProposalInstruction::SetErrorHandler(vec![]),
+        ])]);
+        assert_eq!(build_xcm_program(&nested), Err(DaoError::NestedXcmProgram));
+
+        let handler = vec![ProposalInstruction::RefundSurplus; MAX_PROGRAM_LENGTH - 2];
+        let too_long = paid(vec![ProposalInstruction::SetErrorHandler(handler)]);
+        assert_eq!(build_xcm_program(&too_long), Err(DaoError::XcmProgramTooLong));
+    }
+
+    #[test]
+    fn test_transfer_program() {
+        let beneficiary = Location::new(0, [AccountId32 { network: None, id: [1; 32] }]);
+        let usdt = Location::new(0, [PalletInstance(50), GeneralIndex(1984)]);
+
+        let local = transfer_program(ASSET_HUB, beneficiary.clone(), usdt.clone(), 100, 10).unwrap();
+        assert!(matches!(local[2], ProposalInstruction::DepositAsset { .. }));
+
+        let teleport =
+            transfer_program(Chain::Relay, beneficiary.clone(), Location::parent(), 100, 10).unwrap();
+        assert!(matches!(teleport[2], ProposalInstruction::InitiateTeleport { .. }));
+
+        let reserve = transfer_program(Chain::Parachain(4001), beneficiary.clone(), usdt.clone(), 100, 10).unwrap();
+        let ProposalInstruction::DepositReserveAsset { xcm, .. } = &reserve[2] else {
+            panic!("Expected a reserve transfer");
+        };
+        assert_eq!(
+            xcm[0],
+            ProposalInstruction::BuyExecution {
+                asset: Location::new(1, [Parachain(1000), PalletInstance(50), GeneralIndex(1984)]),
+                amount: 10,
+            }
+        );
+        assert!(build_xcm_program(&reserve).is_ok());
+
+        assert_eq!(
+            transfer_program(Chain::Relay, beneficiary.clone(), Location::parent(), 100, 51),
+            Err(DaoError::FeeExceedsAmount)
+        );
+        assert_eq!(
+            transfer_program(Chain::Relay, beneficiary, usdt, 100, 10),
+            Err(DaoError::UnsupportedAsset)
+        );
+    }
+
+    #[test]
+    fn test_transact_program() {
+        let report_to = Location::new(1, [Parachain(POP_PARA_ID), AccountId32 { network: None, id: [1; 32] }]);
+        let refund_to = Location::new(1, [Parachain(POP_PARA_ID), AccountId32 { network: None, id: [2; 32] }]);
+        let weight = Weight::from_parts(1_000, 2_000);
+        let program = transact_program(
+            Location::parent(),
+            100,
+            OriginKind::SovereignAccount,
+            weight,
+            vec![0, 0],
+            report_to.clone(),
+            7,
+            refund_to.clone(),
+        );
+
+        // Errors are reported and fees refunded from the appendix, which runs even if the `Transact` fails.
+        assert_eq!(
+            program,
+            vec![
+                ProposalInstruction::WithdrawAsset { asset: Location::parent(), amount: 100 },
+                ProposalInstruction::BuyExecution { asset: Location::parent(), amount: 100 },
+                ProposalInstruction::SetAppendix(vec![
+                    ProposalInstruction::ReportError {
+                        destination: report_to.clone(),
+                        query_id: 7,
+                        ref_time: 0,
+                        proof_size: 0,
+                    },
+                    ProposalInstruction::RefundSurplus,
+                    ProposalInstruction::DepositAsset { max_assets: 1, beneficiary: refund_to },
+                ]),
+                ProposalInstruction::Transact {
+                    origin_kind: OriginKind::SovereignAccount,
+                    ref_time: 1_000,
+                    proof_size: 2_000,
+                    call: vec![0, 0],
+                },
+                ProposalInstruction::ReportTransactStatus {
+                    destination: report_to,
+                    query_id: 7,
+                    ref_time: 0,
+                    proof_size: 0,
+                },
+            ]
+        );
+        let message = build_xcm_program(&program).unwrap();
+        assert!(matches!(message.0.last(), Some(Instruction::ReportTransactStatus(_))));
+    }
+
+    fn transact(ref_time: u64, call: Vec<u8>) -> ProposalInstruction {
+        ProposalInstruction::Transact {
+            origin_kind: OriginKind::SovereignAccount,
+            ref_time,
+            proof_size: 0,
+            call,
+        }
+    }
+
+    #[test]
+    fn test_fee_table() {
+        let fees = FeeTable {
+            base_weight: Weight::from_parts(1_000, 10),
+            weight_per_byte: Weight::from_parts(100, 1),
+            base_fee: 5,
+            fee_per_second: REF_TIME_PER_SECOND as Balance,
+            max_weight: Weight::from_parts(2_000, 20),
+        };
+        assert_eq!(fees.estimate(4), (Weight::from_parts(1_400, 14), 1_405));
+
+        let config = DestinationConfig { fees, ..DestinationConfig::new(Location::parent()) };
+        assert!(config.validate(&paid(vec![transact(2_000, vec![0, 0])])).is_ok());
+        assert_eq!(
+            config.validate(&paid(vec![ProposalInstruction::SetErrorHandler(vec![transact(
+                2_001,
+                vec![0, 0]
+            )])])),
+            Err(DaoError::WeightLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn test_validate_origin_kinds() {
+        let mut config = DestinationConfig::new(Location::parent());
+        let xcm_origin = ProposalInstruction::Transact {
+            origin_kind: OriginKind::Xcm,
+            ref_time: 0,
+            proof_size: 0,
+            call: vec![0, 0],
+        };
+        assert!(config.validate(&paid(vec![transact(0, vec![0, 0])])).is_ok());
+        assert_eq!(
+            config.validate(&paid(vec![xcm_origin.clone()])),
+            Err(DaoError::OriginKindNotAllowed)
+        );
+
+        config.allowed_origin_kinds.push(OriginKind::Xcm);
+        assert!(config.validate(&paid(vec![xcm_origin])).is_ok());
+    }
+
+    #[test]
+    fn test_validate_calls() {
+        let config = DestinationConfig {
+            allowed_calls: Some(vec![(10, 3)]),
+            ..DestinationConfig::new(Location::parent())
+        };
+        assert!(config.validate(&paid(vec![transact(0, vec![10, 3, 42])])).is_ok());
+        assert_eq!(config.validate(&paid(vec![transact(0, vec![])])), Err(DaoError::EmptyCall));
+        assert_eq!(
+            config.validate(&paid(vec![transact(0, vec![10; MAX_CALL_LEN + 1])])),
+            Err(DaoError::CallTooLarge)
+        );
+        assert_eq!(
+            config.validate(&paid(vec![transact(0, vec![10, 4])])),
+            Err(DaoError::CallNotAllowed)
+        );
+        assert_eq!(config.validate(&paid(vec![transact(0, vec![10])])), Err(DaoError::CallNotAllowed));
+    }
+
+    #[test]
+    fn test_validate_local() {
+        let config = DestinationConfig::local();
+        let half = LOCAL_WEIGHT_LIMIT.ref_time() / 2;
+        assert!(config.validate_local(&paid(vec![transact(half, vec![0, 0])])).is_ok());
+        assert_eq!(
+            config.validate_local(&paid(vec![
+                transact(half, vec![0, 0]),
+                ProposalInstruction::SetAppendix(vec![transact(half + 1, vec![0, 0])]),
+            ])),
+            Err(DaoError::WeightLimitExceeded)
+        );
+    }
+
+    #[test]
+    fn test_reached_chains() {
+        let pop = Chain::Parachain(POP_PARA_ID);
+        let account = AccountId32 { network: None, id: [1; 32] };
+        let program = paid(vec![
+            ProposalInstruction::TransferAsset {
+                asset: Location::parent(),
+                amount: 10,
+                beneficiary: Location::new(0, [account]),
+            },
+            ProposalInstruction::SetErrorHandler(vec![ProposalInstruction::DepositAsset {
+                max_assets: 1,
+                beneficiary: Location::new(1, [Parachain(2000), account]),
+            }]),
+            ProposalInstruction::InitiateTeleport {
+                max_assets: 1,
+                dest: Location::parent(),
+                xcm: vec![ProposalInstruction::DepositAsset {
+                    max_assets: 1,
+                    beneficiary: Location::new(0, [Parachain(3000), account]),
+                }],
+            },
+        ]);
+        assert_eq!(
+            reached_chains(pop, &program),
+            Ok(vec![pop, Chain::Parachain(2000), Chain::Relay, Chain::Parachain(3000)])
+        );
+
+        let grandparent = paid(vec![ProposalInstruction::DepositAsset {
+            max_assets: 1,
+            beneficiary: Location::new(2, [account]),
+        }]);
+        assert_eq!(reached_chains(pop, &grandparent), Err(DaoError::UnsupportedDestination));
+    }
+
+    #[test]
+    fn test_record_response() {
+        let forwarded = |query_id| {
+            ForwardedProposal::new(None, ASSET_HUB, Location::parent(), 0, Weight::zero(), &[], query_id)
+                .unwrap()
+        };
+
+        let mut proposal = forwarded(0);
+        assert_eq!(proposal.record_response(&Response::DispatchResult(MaybeErrorCode::Success)), Ok(false));
+        assert_eq!(proposal.record_response(&Response::ExecutionResult(None)), Ok(true));
+        assert_eq!(proposal.status, ExecutionStatus::Succeeded);
+
+        let mut proposal = forwarded(1);
+        let error = MaybeErrorCode::Error(Default::default());
+        assert_eq!(proposal.record_response(&Response::DispatchResult(error)), Ok(false));
+        assert_eq!(proposal.record_response(&Response::ExecutionResult(None)), Ok(true));
+        assert_eq!(proposal.status, ExecutionStatus::Failed);
+
+        assert_eq!(proposal.record_response(&Response::Null), Err(DaoError::UnexpectedResponse));
+    }
+
+    #[test]
+    fn test_local_proposal_is_passed() {
+        let [alice, bob, charlie] = [[1; 32], [2; 32], [3; 32]].map(AccountId::from);
+        let proposal = LocalProposal {
+            dest: None,
+            program: Vec::new(),
+            ayes: vec![alice, bob],
+            nays: Vec::new(),
+            executed: false,
+        };
+        assert!(proposal.is_passed(3, |_| true));
+        assert!(!proposal.is_passed(4, |_| true));
+
+        // Bob left the Dao after voting.
+        assert!(!proposal.is_passed(2, |voter| [alice, charlie].contains(voter)));
+    }
+
+    #[test]
+    fn test_proposal_summary() {
+        let weight = Weight::from_parts(1_000, 10);
+        let label = String::from("Fund the treasury");
+        let proposal =
+            ForwardedProposal::new(Some(label.clone()), ASSET_HUB, Location::parent(), 5, weight, &[10, 3, 42], 0)
+                .unwrap();
+        let summary = proposal.summary();
+        assert_eq!(summary.label, Some(label));
+        assert_eq!(summary.dest, Location::new(1, [Parachain(1000)]));
+        assert_eq!(summary.weight, weight);
+        assert_eq!((summary.pallet_index, summary.call_index), (Some(10), Some(3)));
+        assert_eq!(summary.status, ExecutionStatus::Pending);
+
+        let program = paid(vec![transact(1_000, vec![10, 3, 42]), transact(2_000, vec![11, 0])]);
+        let summary = ForwardedProposal::from_program(Some(String::from("Upgrade")), ASSET_HUB, &program, 0)
+            .unwrap()
+            .summary();
+        assert_eq!(summary.label, Some(String::from("Upgrade")));
+        assert_eq!((summary.fee_asset, summary.fee_max), (Location::parent(), 100));
+        assert_eq!(summary.weight, Weight::from_parts(1_000, 0));
+        assert_eq!((summary.pallet_index, summary.call_index), (Some(10), Some(3)));
+
+        let transfer = paid(vec![ProposalInstruction::DepositAsset { max_assets: 1, beneficiary: Location::here() }]);
+        let summary = ForwardedProposal::from_program(None, ASSET_HUB, &transfer, 0).unwrap().summary();
+        assert_eq!((summary.weight, summary.pallet_index), (Weight::zero(), None));
+
+        let long_label = String::from_utf8(vec![b'a'; MAX_LABEL_LEN + 1]).unwrap();
+        assert_eq!(
+            ForwardedProposal::new(Some(long_label), ASSET_HUB, Location::parent(), 5, weight, &[], 0),
+            Err(DaoError::LabelTooLong)
+        );
+    }
+}
@@ -0,0 +1,367 @@
+use ink::{
+    prelude::{string::String, vec::Vec},
+    storage::Mapping,
+    xcm::prelude::*,
+};
+use superdao_traits::{Call, ChainCall, Error as SuperdaoError};
+
+use crate::{
+    build_xcm_program, reached_chains, transact_program, transfer_program, AccountId, Balance, Chain, DaoError,
+    DestinationConfig, FeeTable, ForwardedProposal, LocalProposal, ProposalInstruction, ASSET_HUB, POP_PARA_ID,
+};
+
+/// Cross-chain state of a Dao: the destinations its proposals may target, the proposals it
+/// forwarded to the Superdao and the XCM proposals voted on by its own members.
+///
+/// Access control is left to the contract, which must check the caller before mutating the state.
+#[ink::storage_item]
+pub struct CrossChainState {
+    destinations: Mapping<Chain, DestinationConfig>,
+    proposals: Mapping<u32, ForwardedProposal>,
+    queries: Mapping<u64, u32>,
+    next_query_id: u64,
+    query_responder: Option<AccountId>,
+    local_proposals: Mapping<u32, LocalProposal>,
+    next_local_proposal_id: u32,
+    local_config: DestinationConfig,
+}
+
+/// A Superdao proposal built by the Dao, waiting to be created on the Superdao.
+pub struct PreparedProposal {
+    call: Call,
+    record: ForwardedProposal,
+    // Whether the program reports its outcome under the record's query id.
+    reports: bool,
+}
+
+impl CrossChainState {
+    /// Returns the state of a new Dao, which may only target Asset Hub, paying fees in DOT.
+    pub fn new() -> Self {
+        let mut state = Self {
+            destinations: Mapping::new(),
+            proposals: Mapping::new(),
+            queries: Mapping::new(),
+            next_query_id: 0,
+            query_responder: None,
+            local_proposals: Mapping::new(),
+            next_local_proposal_id: 0,
+            local_config: DestinationConfig::local(),
+        };
+        state.destinations.insert(ASSET_HUB, &DestinationConfig::new(Location::parent()));
+        state
+    }
+
+    /// Returns the chain `dest` refers to and its config.
+    ///
+    /// Fails if `dest` is not the relay chain or a parachain, or is not in the allowlist.
+    pub fn destination(&self, dest: &Location) -> Result<(Chain, DestinationConfig), DaoError> {
+        let chain = Chain::try_from(dest)?;
+        let config = self.destinations.get(chain).ok_or(DaoError::DestinationNotAllowed)?;
+        Ok((chain, config))
+    }
+
+    /// Adds `dest` to the allowlist, paying fees with `fee_asset` by default. Only the default fee
+    /// asset is updated if `dest` is already allowed.
+    pub fn allow_destination(&mut self, dest: &Location, fee_asset: Location) -> Result<(), DaoError> {
+        let chain = Chain::try_from(dest)?;
+        let config = match self.destinations.get(chain) {
+            Some(config) => DestinationConfig { fee_asset, ..config },
+            None => DestinationConfig::new(fee_asset),
+        };
+        self.destinations.insert(chain, &config);
+        Ok(())
+    }
+
+    /// Removes `dest` from the allowlist.
+    pub fn disallow_destination(&mut self, dest: &Location) -> Result<(), DaoError> {
+        let (chain, _) = self.destination(dest)?;
+        self.destinations.remove(chain);
+        Ok(())
+    }
+
+    /// Replaces the weights, fee rates and weight limit used for proposals to `dest`.
+    pub fn set_fee_table(&mut self, dest: &Location, fees: FeeTable) -> Result<(), DaoError> {
+        self.update_destination(dest, |config| config.fees = fees)
+    }
+
+    /// Restricts the calls proposals may dispatch on `dest`, or lifts the restriction with `None`.
+    pub fn set_allowed_calls(&mut self, dest: &Location, allowed_calls: Option<Vec<(u8, u8)>>) -> Result<(), DaoError> {
+        self.update_destination(dest, |config| config.allowed_calls = allowed_calls)
+    }
+
+    /// Replaces the origin kinds proposals may dispatch calls with on `dest`.
+    pub fn set_allowed_origin_kinds(&mut self, dest: &Location, origin_kinds: Vec<OriginKind>) -> Result<(), DaoError> {
+        self.update_destination(dest, |config| config.allowed_origin_kinds = origin_kinds)
+    }
+
+    /// Limit on the total weight of the `Transact`s in a Dao proposal executed on Pop Network.
+    pub fn local_weight_limit(&self) -> Weight {
+        self.local_config.fees.max_weight
+    }
+
+    /// Replaces the limit on the total weight of the `Transact`s in a Dao proposal executed on Pop
+    /// Network.
+    pub fn set_local_weight_limit(&mut self, weight: Weight) {
+        self.local_config.fees.max_weight = weight;
+    }
+
+    /// Returns the recommended weight and fee for dispatching `encoded_extrinsic` on `dest`.
+    ///
+    /// Fails if the estimated weight exceeds the destination's limit.
+    pub fn estimate(&self, dest: &Location, encoded_extrinsic: &[u8]) -> Result<(Weight, Balance), DaoError> {
+        let (_, config) = self.destination(dest)?;
+        let (weight, fee) = config.fees.estimate(encoded_extrinsic.len());
+        if weight.any_gt(config.fees.max_weight) {
+            return Err(DaoError::WeightLimitExceeded);
+        }
+        Ok((weight, fee))
+    }
+
+    /// Builds a proposal dispatching `encoded_extrinsic` on `dest`, paying fees with `fee_asset` or
+    /// the destination's default fee asset.
+    ///
+    /// Unspent fees are refunded to the Superdao's sovereign account and the execution outcome is
+    /// reported back to `dao`.
+    #[allow(clippy::too_many_arguments)]
+    pub fn transact_proposal(
+        &self,
+        dest: &Location,
+        encoded_extrinsic: Vec<u8>,
+        origin_kind: OriginKind,
+        fee_asset: Option<Location>,
+        fee_max: Balance,
+        weight: Weight,
+        label: Option<String>,
+        dao: AccountId,
+        superdao: AccountId,
+    ) -> Result<PreparedProposal, DaoError> {
+        let (chain, config) = self.destination(dest)?;
+        let fee_asset = fee_asset.unwrap_or(config.fee_asset.clone());
+        let query_id = self.next_query_id;
+        let record =
+            ForwardedProposal::new(label, chain, fee_asset.clone(), fee_max, weight, &encoded_extrinsic, query_id)?;
+        let program = transact_program(
+            fee_asset,
+            fee_max,
+            origin_kind,
+            weight,
+            encoded_extrinsic,
+            chain.location_of(dao),
+            query_id,
+            chain.location_of(superdao),
+        );
+        config.validate(&program)?;
+        Ok(PreparedProposal { call: chain_call(chain, &program)?, record, reports: true })
+    }
+
+    /// Builds a proposal running `program` on `dest`.
+    pub fn xcm_proposal(
+        &self,
+        dest: &Location,
+        program: Vec<ProposalInstruction>,
+        label: Option<String>,
+    ) -> Result<PreparedProposal, DaoError> {
+        let (chain, config) = self.destination(dest)?;
+        config.validate(&program)?;
+        self.ensure_chains_allowed(chain, &program)?;
+        let call = chain_call(chain, &program)?;
+        let record = ForwardedProposal::from_program(label, chain, &program, self.next_query_id)?;
+        Ok(PreparedProposal { call, record, reports: false })
+    }
+
+    /// Builds a proposal paying `amount` of `asset` from the Superdao's treasury on Asset Hub to
+    /// `beneficiary` on `dest_chain`.
+    pub fn transfer_proposal(
+        &self,
+        dest_chain: &Location,
+        beneficiary: Location,
+        asset: Location,
+        amount: Balance,
+        fee_max: Balance,
+        label: Option<String>,
+    ) -> Result<PreparedProposal, DaoError> {
+        let (treasury, config) = self.destination(&ASSET_HUB.location())?;
+        let (dest_chain, _) = self.destination(dest_chain)?;
+        let program = transfer_program(dest_chain, beneficiary, asset, amount, fee_max)?;
+        config.validate(&program)?;
+        self.ensure_chains_allowed(treasury, &program)?;
+        let call = chain_call(treasury, &program)?;
+        let record = ForwardedProposal::from_program(label, treasury, &program, self.next_query_id)?;
+        Ok(PreparedProposal { call, record, reports: false })
+    }
+
+    /// Creates `proposal` on the Superdao with `create_proposal`, recording it under its Superdao
+    /// proposal id if it was created.
+    pub fn forward(
+        &mut self,
+        proposal: PreparedProposal,
+        create_proposal: impl FnOnce(Call) -> Result<u32, SuperdaoError>,
+    ) -> Result<u32, SuperdaoError> {
+        let PreparedProposal { call, record, reports } = proposal;
+        let proposal_id = create_proposal(call)?;
+        // Programs the Dao did not build report nothing under the query id, so their status stays
+        // pending.
+        if reports {
+            self.queries.insert(record.query_id, &proposal_id);
+        }
+        self.next_query_id = record.query_id.wrapping_add(1);
+        self.proposals.insert(proposal_id, &record);
+        Ok(proposal_id)
+    }
+
+    /// Returns the record of a proposal forwarded to the Superdao.
+    pub fn forwarded_proposal(&self, proposal_id: u32) -> Result<ForwardedProposal, DaoError> {
+        self.proposals.get(proposal_id).ok_or(DaoError::ProposalDoesNotExist)
+    }
+
+    /// Replaces the account trusted to record query responses, or removes it with `None`.
+    pub fn set_query_responder(&mut self, responder: Option<AccountId>) {
+        self.query_responder = responder;
+    }
+
+    /// Records a query response relayed by `caller` on the forwarded proposal awaiting it.
+    ///
+    /// Fails unless `caller` is the query responder, whom the Dao trusts to relay what the
+    /// destination actually sent.
+    pub fn record_query_response(
+        &mut self,
+        caller: AccountId,
+        query_id: u64,
+        response: &Response,
+    ) -> Result<(), DaoError> {
+        if self.query_responder != Some(caller) {
+            return Err(DaoError::Unauthorized);
+        }
+        let proposal_id = self.queries.get(query_id).ok_or(DaoError::QueryNotFound)?;
+        let mut proposal = self.forwarded_proposal(proposal_id)?;
+        if proposal.record_response(response)? {
+            self.queries.remove(query_id);
+        }
+        self.proposals.insert(proposal_id, &proposal);
+        Ok(())
+    }
+
+    /// Creates a Dao proposal to send `program` to `dest`, or to execute it on Pop Network if there
+    /// is no `dest`, and returns its id.
+    pub fn create_dao_proposal(
+        &mut self,
+        dest: Option<Location>,
+        program: Vec<ProposalInstruction>,
+    ) -> Result<u32, DaoError> {
+        let dest = dest.as_ref().map(Chain::try_from).transpose()?;
+        self.ensure_program_allowed(dest, &program)?;
+        build_xcm_program(&program)?;
+
+        let proposal_id = self.next_local_proposal_id;
+        self.local_proposals.insert(
+            proposal_id,
+            &LocalProposal { dest, program, ayes: Vec::new(), nays: Vec::new(), executed: false },
+        );
+        self.next_local_proposal_id = proposal_id.wrapping_add(1);
+        Ok(proposal_id)
+    }
+
+    /// Returns a Dao proposal.
+    pub fn dao_proposal(&self, proposal_id: u32) -> Result<LocalProposal, DaoError> {
+        self.local_proposals.get(proposal_id).ok_or(DaoError::ProposalDoesNotExist)
+    }
+
+    /// Records the vote of `voter` on a Dao proposal that has not been executed yet.
+    pub fn vote_dao_proposal(&mut self, proposal_id: u32, voter: AccountId, vote: bool) -> Result<(), DaoError> {
+        let mut proposal = self.dao_proposal(proposal_id)?;
+        if proposal.executed {
+            return Err(DaoError::ProposalAlreadyExecuted);
+        }
+        if proposal.has_voted(&voter) {
+            return Err(DaoError::VoterAlreadyVoted);
+        }
+
+        if vote {
+            proposal.ayes.push(voter);
+        } else {
+            proposal.nays.push(voter);
+        }
+        self.local_proposals.insert(proposal_id, &proposal);
+        Ok(())
+    }
+
+    /// Returns the destination and message of a Dao proposal that a majority of the current
+    /// `member_count` members approved, ready to be sent or executed.
+    ///
+    /// The program is checked again, as the allowlist and weight limits may have changed since the
+    /// proposal was created.
+    pub fn passed_dao_proposal(
+        &self,
+        proposal_id: u32,
+        member_count: u32,
+        is_member: impl Fn(&AccountId) -> bool,
+    ) -> Result<(Option<Chain>, Xcm<()>), DaoError> {
+        let proposal = self.dao_proposal(proposal_id)?;
+        if proposal.executed {
+            return Err(DaoError::ProposalAlreadyExecuted);
+        }
+        if !proposal.is_passed(member_count, is_member) {
+            return Err(DaoError::ProposalNotPassed);
+        }
+        self.ensure_program_allowed(proposal.dest, &proposal.program)?;
+        Ok((proposal.dest, build_xcm_program(&proposal.program)?))
+    }
+
+    /// Marks a Dao proposal as executed, so that it cannot be voted on or executed again.
+    pub fn mark_dao_proposal_executed(&mut self, proposal_id: u32) -> Result<(), DaoError> {
+        let mut proposal = self.dao_proposal(proposal_id)?;
+        proposal.executed = true;
+        self.local_proposals.insert(proposal_id, &proposal);
+        Ok(())
+    }
+
+    fn update_destination(
+        &mut self,
+        dest: &Location,
+        update: impl FnOnce(&mut DestinationConfig),
+    ) -> Result<(), DaoError> {
+        let (chain, mut config) = self.destination(dest)?;
+        update(&mut config);
+        self.destinations.insert(chain, &config);
+        Ok(())
+    }
+
+    // Checks a Dao proposal's program against the config of `dest`, or against the local config
+    // if the program is executed on Pop Network.
+    fn ensure_program_allowed(&self, dest: Option<Chain>, program: &[ProposalInstruction]) -> Result<(), DaoError> {
+        let chain = match dest {
+            Some(chain) => {
+                let (_, config) = self.destination(&chain.location())?;
+                config.validate(program)?;
+                chain
+            },
+            None => {
+                self.local_config.validate_local(program)?;
+                Chain::Parachain(POP_PARA_ID)
+            },
+        };
+        self.ensure_chains_allowed(chain, program)
+    }
+
+    fn ensure_chains_allowed(&self, chain: Chain, program: &[ProposalInstruction]) -> Result<(), DaoError> {
+        for reached in reached_chains(chain, program)? {
+            // Pop Network is always reachable, so that programs can report back to the Dao.
+            if reached != Chain::Parachain(POP_PARA_ID) && !self.destinations.contains(reached) {
+                return Err(DaoError::DestinationNotAllowed);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Default for CrossChainState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Returns the Superdao call sending `program` to `chain`.
+fn chain_call(chain: Chain, program: &[ProposalInstruction]) -> Result<Call, DaoError> {
+    let message = build_xcm_program(program)?;
+    Ok(Call::Chain(ChainCall::new(&chain.location(), &message)))
+}
@@ -3,6 +3,14 @@
 use ink::env::{DefaultEnvironment, Environment};
 use superdao_traits::Error as SuperdaoError;
 
+pub use cross_chain::*;
+pub use cross_chain_state::*;
+pub use treasury::*;
+
+mod cross_chain;
+mod cross_chain_state;
+mod treasury;
+
 pub type AccountId = <DefaultEnvironment as Environment>::AccountId;
 pub type Balance = <DefaultEnvironment as Environment>::Balance;
 pub type BlockNumber = <DefaultEnvironment as Environment>::BlockNumber;
@@ -24,6 +32,74 @@ pub enum DaoError {
     NoContractAddress,
     // Error derived from Superdao contract.
     SuperdaoError(SuperdaoError),
+    // Caller is not allowed to perform the action.
+    Unauthorized,
+    // Destination is neither the relay chain nor a sibling parachain.
+    UnsupportedDestination,
+    // Destination is not in the Dao's allowlist.
+    DestinationNotAllowed,
+    // XCM program has no instructions.
+    EmptyXcmProgram,
+    // XCM program does not start by withdrawing an asset and buying execution.
+    XcmProgramNotPaid,
+    // XCM program nests a program inside another nested one.
+    NestedXcmProgram,
+    // XCM program has more instructions than allowed.
+    XcmProgramTooLong,
+    // Asset location cannot be expressed on the destination.
+    UnsupportedAsset,
+    // Fees may consume more than the transferred amount.
+    FeeExceedsAmount,
+    // Required weight exceeds the destination's limit.
+    WeightLimitExceeded,
+    // Query is not pending in the Dao.
+    QueryNotFound,
+    // Query response is not a transact status or error report.
+    UnexpectedResponse,
+    // Proposal has not been approved by a majority of voters.
+    ProposalNotPassed,
+    // Proposal has already been executed.
+    ProposalAlreadyExecuted,
+    // XCM message could not be sent or executed.
+    XcmFailed,
+    // Encoded call is empty.
+    EmptyCall,
+    // Encoded call is larger than allowed.
+    CallTooLarge,
+    // Encoded call's pallet and call index are not in the destination's allowlist.
+    CallNotAllowed,
+    // Proposal label is longer than allowed.
+    LabelTooLong,
+    // Origin kind is not allowed by the destination's policy.
+    OriginKindNotAllowed,
+    // Value exceeds the Dao's budget.
+    BudgetExceeded,
+    // Treasury does not hold enough free balance for the payout.
+    InsufficientTreasury,
+    // Payout exceeds what the treasury may still spend this period.
+    SpendingCapExceeded,
+    // Native balance transfer from the treasury failed.
+    TreasuryTransferFailed,
+    // Prevote period is already ended.
+    PrevotePeriodEnded,
+    // PSP22 token transfer from the treasury failed.
+    TokenTransferFailed,
+    // Vesting cliff falls after the end of the schedule.
+    InvalidVestingSchedule,
+    // Stream does not exist in the Dao.
+    StreamDoesNotExist,
+    // Stream has already been cancelled.
+    StreamAlreadyCancelled,
+    // Caller has no vested amount left to claim.
+    NothingToClaim,
+    // Treasury already tracks the maximum number of tokens.
+    TreasuryTokenLimitReached,
+    // Voter has not deposited enough governance tokens.
+    InsufficientVotingDeposit,
+    // Voting deposit backs a prevote that is still open.
+    VotingDepositLocked,
+    // Fungible token does not exist in the runtime.
+    TokenDoesNotExist,
 }
 
 impl From<SuperdaoError> for DaoError {
@@ -31,3 +107,13 @@ impl From<SuperdaoError> for DaoError {
         Self::SuperdaoError(error)
     }
 }
+
+/// Callbacks the Superdao may invoke on a member Dao when executing an approved proposal.
+///
+/// Implementations must return `DaoError::Unauthorized` unless the caller is the Superdao.
+#[ink::trait_definition]
+pub trait SuperdaoExecutable {
+    /// Increases the Dao's value by 10.
+    #[ink(message)]
+    fn update_value(&mut self) -> Result<(), DaoError>;
+}
@@ -0,0 +1,142 @@
+use crate::{Balance, BlockNumber, DaoError};
+
+// The challenge-7 PSP22 contract keeps its own copy as `MintAllowance`, as it builds against a
+// different ink! version. Keep the period arithmetic of both in sync.
+
+/// Caps how much a Dao treasury may pay out within a rolling window of blocks.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct SpendingLimit {
+    /// Maximum amount paid out per period.
+    pub cap: Balance,
+    /// Length of a period in blocks.
+    pub period: BlockNumber,
+    /// Block at which the current period started.
+    pub period_start: BlockNumber,
+    /// Amount already paid out in the current period.
+    pub spent: Balance,
+}
+
+impl SpendingLimit {
+    /// Creates a limit whose first period starts at block `now`.
+    pub fn new(cap: Balance, period: BlockNumber, now: BlockNumber) -> Self {
+        Self { cap, period, period_start: now, spent: 0 }
+    }
+
+    /// Amount that can still be paid out at block `now` before the cap is reached.
+    pub fn remaining(&self, now: BlockNumber) -> Balance {
+        if self.is_period_over(now) {
+            self.cap
+        } else {
+            self.cap.saturating_sub(self.spent)
+        }
+    }
+
+    /// Records a payout of `amount` at block `now`, starting a new period if the current one is over.
+    pub fn record(&mut self, now: BlockNumber, amount: Balance) -> Result<(), DaoError> {
+        if amount > self.remaining(now) {
+            return Err(DaoError::SpendingCapExceeded);
+        }
+        if self.is_period_over(now) {
+            // Periods stay aligned to the block at which the limit was set.
+            let elapsed = now.saturating_sub(self.period_start);
+            self.period_start = now.saturating_sub(elapsed % self.period);
+            self.spent = 0;
+        }
+        self.spent = self.spent.saturating_add(amount);
+        Ok(())
+    }
+
+    fn is_period_over(&self, now: BlockNumber) -> bool {
+        self.period > 0 && now >= self.period_start.saturating_add(self.period)
+    }
+}
+
+/// Releases `total` linearly over `duration` blocks from `start`, with nothing released before the cliff.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[ink::scale_derive(Encode, Decode, TypeInfo)]
+#[cfg_attr(feature = "std", derive(ink::storage::traits::StorageLayout))]
+pub struct VestingSchedule {
+    /// Block at which vesting starts.
+    pub start: BlockNumber,
+    /// Number of blocks after `start` before anything vests.
+    pub cliff: BlockNumber,
+    /// Number of blocks after `start` at which everything has vested.
+    pub duration: BlockNumber,
+    /// Amount released over the whole schedule.
+    pub total: Balance,
+}
+
+impl VestingSchedule {
+    /// Checks that the cliff does not fall after the end of the schedule.
+    pub fn validate(&self) -> Result<(), DaoError> {
+        if self.cliff > self.duration {
+            return Err(DaoError::InvalidVestingSchedule);
+        }
+        Ok(())
+    }
+
+    /// Amount vested at block `now`.
+    pub fn vested_at(&self, now: BlockNumber) -> Balance {
+        let elapsed = now.saturating_sub(self.start);
+        if now < self.start || elapsed < self.cliff {
+            return 0;
+        }
+        if elapsed >= self.duration {
+            return self.total;
+        }
+        self.total
+            .saturating_mul(Balance::from(elapsed))
+            .checked_div(Balance::from(self.duration))
+            .unwrap_or(self.total)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spending_limit() {
+        let mut limit = SpendingLimit::new(100, 10, 5);
+        assert_eq!(limit.remaining(5), 100);
+        assert_eq!(limit.record(6, 60), Ok(()));
+        assert_eq!(limit.record(14, 41), Err(DaoError::SpendingCapExceeded));
+        assert_eq!(limit.record(14, 40), Ok(()));
+        assert_eq!(limit.remaining(14), 0);
+
+        // A new period starts on a multiple of `period` after the original start.
+        assert_eq!(limit.remaining(27), 100);
+        assert_eq!(limit.record(27, 30), Ok(()));
+        assert_eq!(limit.period_start, 25);
+        assert_eq!(limit.remaining(34), 70);
+        assert_eq!(limit.remaining(35), 100);
+    }
+
+    #[test]
+    fn test_spending_limit_without_period() {
+        let mut limit = SpendingLimit::new(50, 0, 0);
+        assert_eq!(limit.record(1_000, 50), Ok(()));
+        assert_eq!(limit.record(1_000_000, 1), Err(DaoError::SpendingCapExceeded));
+    }
+
+    #[test]
+    fn test_vesting_schedule() {
+        let schedule = VestingSchedule { start: 10, cliff: 20, duration: 100, total: 1_000 };
+        assert_eq!(schedule.validate(), Ok(()));
+        assert_eq!(schedule.vested_at(0), 0);
+        assert_eq!(schedule.vested_at(29), 0);
+        assert_eq!(schedule.vested_at(30), 200);
+        assert_eq!(schedule.vested_at(60), 500);
+        assert_eq!(schedule.vested_at(110), 1_000);
+        assert_eq!(schedule.vested_at(1_000), 1_000);
+
+        let immediate = VestingSchedule { start: 10, cliff: 0, duration: 0, total: 1_000 };
+        assert_eq!(immediate.vested_at(9), 0);
+        assert_eq!(immediate.vested_at(10), 1_000);
+
+        let invalid = VestingSchedule { start: 10, cliff: 101, duration: 100, total: 1_000 };
+        assert_eq!(invalid.validate(), Err(DaoError::InvalidVestingSchedule));
+    }
+}